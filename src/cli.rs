@@ -84,6 +84,15 @@ pub struct Cli {
     /// Audit core modules for hypergraph synergy
     #[clap(long)]
     pub audit_hypergraph: bool,
+    /// Print the hypergraph health report
+    #[clap(long)]
+    pub hypergraph_report: bool,
+    /// Print the hypergraph report as JSON
+    #[clap(long, requires = "hypergraph_report")]
+    pub json: bool,
+    /// Re-render the hypergraph report every SECONDS until interrupted
+    #[clap(long, value_name = "SECONDS", requires = "hypergraph_report")]
+    pub watch: Option<u64>,
     /// Input text
     #[clap(trailing_var_arg = true)]
     text: Vec<String>,
@@ -115,6 +115,16 @@ macro_rules! register_client {
             names.iter().collect()
         }
 
+        impl ClientConfig {
+            /// Configured client name, or `None` for an unrecognized client type
+            pub fn name(&self) -> Option<&str> {
+                match self {
+                    $(ClientConfig::$config(c) => Some($client::name(c)),)+
+                    ClientConfig::Unknown => None,
+                }
+            }
+        }
+
         static ALL_MODELS: std::sync::OnceLock<Vec<$crate::client::Model>> = std::sync::OnceLock::new();
 
         pub fn list_all_models(config: &$crate::config::Config) -> Vec<&'static $crate::client::Model> {
@@ -61,3 +61,20 @@ pub const OPENAI_COMPATIBLE_PROVIDERS: [(&str, &str); 19] = [
     ("jina", "https://api.jina.ai/v1"),
     ("voyageai", "https://api.voyageai.com/v1"),
 ];
+
+impl ClientConfig {
+    /// Explicitly configured API base, for client types that accept one
+    pub fn api_base(&self) -> Option<&str> {
+        match self {
+            ClientConfig::OpenAIConfig(c) => c.api_base.as_deref(),
+            ClientConfig::OpenAICompatibleConfig(c) => c.api_base.as_deref(),
+            ClientConfig::GeminiConfig(c) => c.api_base.as_deref(),
+            ClientConfig::ClaudeConfig(c) => c.api_base.as_deref(),
+            ClientConfig::CohereConfig(c) => c.api_base.as_deref(),
+            ClientConfig::AzureOpenAIConfig(c) => c.api_base.as_deref(),
+            ClientConfig::VertexAIConfig(_)
+            | ClientConfig::BedrockConfig(_)
+            | ClientConfig::Unknown => None,
+        }
+    }
+}
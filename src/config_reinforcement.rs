@@ -4,18 +4,92 @@
 //! to strengthen the hypergraph connections between core modules.
 
 use crate::config::GlobalConfig;
-use crate::hypergraph;
+use crate::hypergraph::{self, CoordinatorEvent, HypergraphCoordinator, ModuleAudit, MergeStrengthPolicy, ModuleStatus, NonFiniteStrengthPolicy, ReportOrder, UndeclaredOperationPolicy, UnknownModulePolicy};
 use anyhow::{Result, Context};
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Configuration reinforcement settings for hypergraph optimization
 #[derive(Debug, Clone)]
 pub struct HypergraphConfig {
+    /// Consecutive errors that open a module's circuit breaker (0 = never)
     pub max_module_errors: u64,
     pub synergy_threshold: f64,
     pub audit_interval_seconds: u64,
+    /// Shortest interval the adaptive auditor may tighten to
+    pub min_audit_interval_seconds: u64,
     pub enable_auto_healing: bool,
     pub connection_strength_decay: f64,
+    /// Idle time after which a module is reported as having no recent activity
+    pub stale_activity_seconds: u64,
+    /// Maximum number of undirected edges in the synergy matrix (0 = unbounded)
+    pub max_edges: usize,
+    /// Order of modules in the health report
+    pub report_order: ReportOrder,
+    /// Handling of operation types a module did not declare at registration
+    pub undeclared_operation_policy: UndeclaredOperationPolicy,
+    /// Number of audits retained in the performance time series
+    pub performance_series_capacity: usize,
+    /// Handling of NaN or infinite strengths when scoring synergy
+    pub non_finite_strengths: NonFiniteStrengthPolicy,
+    /// Errors within `error_spike_window_seconds` above which a spike is flagged
+    pub error_spike_threshold: u64,
+    /// Trailing window used for error spike detection
+    pub error_spike_window_seconds: u64,
+    /// Wrap report lines at this many columns (0 = no limit)
+    pub report_max_width: usize,
+    /// Average top retrieval score below which RAG quality is flagged
+    pub min_retrieval_quality: f64,
+    /// Colorize the health report even when stdout is not a terminal
+    pub force_color: bool,
+    /// Minimum error-timeline correlation reported by `correlated_failures`
+    pub failure_correlation_threshold: f64,
+    /// Seconds between autosaved snapshots (0 = disabled)
+    pub autosave_interval_seconds: u64,
+    /// Tolerance applied when comparing scores against thresholds
+    pub comparison_epsilon: f64,
+    /// Drop audit history older than this many seconds (0 = keep until `max_audit_history`)
+    pub retain_history_for_seconds: u64,
+    /// Operations after which a connectionless module is flagged as active but unconnected
+    pub active_unconnected_threshold: u64,
+    /// Infrastructure modules that every other module may connect to, such as `config`
+    pub infrastructure_hubs: Vec<String>,
+    /// Leave infrastructure hubs out of centrality rankings instead of only marking them
+    pub exclude_infrastructure_hubs: bool,
+    /// Operations a module must record before error count alone can make it Critical (0 = no minimum)
+    pub min_operations_for_critical: u64,
+    /// Number of recent significant events kept for `recent_events`
+    pub event_log_capacity: usize,
+    /// Handling of activity recorded for modules that were never registered
+    pub unknown_module_policy: UnknownModulePolicy,
+    /// Cognitive load weight per operation type; others use `DEFAULT_LOAD_FACTOR`
+    pub load_factors: HashMap<String, f64>,
+    /// Cooldown before an open circuit breaker lets a trial call through
+    pub circuit_half_open_seconds: u64,
+    /// Per-module idle seconds overriding `stale_activity_seconds`
+    pub stale_activity_overrides: HashMap<String, u64>,
+    /// Audits per module the synergy trend recommendation looks back over (0 = off)
+    pub synergy_trend_window: usize,
+    /// Audits retained per module, oldest dropped first (0 = unlimited)
+    pub max_audit_history: usize,
+    /// Connections above which a module is flagged as over-connected (0 = never; infrastructure hubs are exempt)
+    pub max_connections_before_warn: usize,
+    /// Share of a module's synergy score given to how many peers it connects to
+    pub connectivity_weight: f64,
+    /// Share of a module's synergy score given to its average connection strength
+    pub strength_weight: f64,
+    /// Strength auto-healing adds to connections below `synergy_threshold` (0 = never strengthen)
+    pub healing_strength_increment: f64,
+    /// Share of operations that may fail before the error rate is flagged
+    pub max_error_rate: f64,
+    /// Seconds an operation may stay in flight before its module is flagged as stuck (0 = never)
+    pub stuck_operation_seconds: u64,
+    /// How `merge_from` combines a connection strength present in both coordinators
+    pub merge_strength_policy: MergeStrengthPolicy,
+    /// Weight of the newest operation in each module's cognitive load moving average, in (0, 1)
+    pub cognitive_load_alpha: f64,
+    /// Weight of the newest operation in the average response time, in (0, 1)
+    pub response_time_alpha: f64,
 }
 
 impl Default for HypergraphConfig {
@@ -24,27 +98,131 @@ impl Default for HypergraphConfig {
             max_module_errors: 20,
             synergy_threshold: 0.6,
             audit_interval_seconds: 300, // 5 minutes
+            min_audit_interval_seconds: 30,
             enable_auto_healing: true,
             connection_strength_decay: 0.95,
+            stale_activity_seconds: 300,
+            max_edges: 0,
+            report_order: ReportOrder::default(),
+            undeclared_operation_policy: UndeclaredOperationPolicy::default(),
+            performance_series_capacity: 120,
+            non_finite_strengths: NonFiniteStrengthPolicy::default(),
+            error_spike_threshold: 5,
+            error_spike_window_seconds: 60,
+            report_max_width: 0,
+            min_retrieval_quality: 0.3,
+            force_color: false,
+            failure_correlation_threshold: 0.8,
+            autosave_interval_seconds: 0,
+            comparison_epsilon: hypergraph::DEFAULT_EPSILON,
+            retain_history_for_seconds: 0,
+            active_unconnected_threshold: 20,
+            infrastructure_hubs: vec!["config".to_string()],
+            exclude_infrastructure_hubs: false,
+            min_operations_for_critical: 0,
+            event_log_capacity: 256,
+            unknown_module_policy: UnknownModulePolicy::default(),
+            load_factors: [
+                ("llm_completion", 0.8),
+                ("embedding", 0.5),
+                ("session_management", 0.3),
+                ("rag_query", 0.6),
+                ("hypergraph_update", 0.9),
+            ]
+            .into_iter()
+            .map(|(operation, factor)| (operation.to_string(), factor))
+            .collect(),
+            circuit_half_open_seconds: 30,
+            stale_activity_overrides: HashMap::new(),
+            synergy_trend_window: 5,
+            max_audit_history: 100,
+            max_connections_before_warn: 8,
+            connectivity_weight: 0.5,
+            strength_weight: 0.5,
+            healing_strength_increment: 0.1,
+            max_error_rate: 0.1,
+            stuck_operation_seconds: 300,
+            merge_strength_policy: MergeStrengthPolicy::default(),
+            cognitive_load_alpha: 0.1,
+            response_time_alpha: 0.1,
         }
     }
 }
 
+impl HypergraphConfig {
+    /// Check settings that must agree with each other
+    ///
+    /// `connectivity_weight` and `strength_weight` must be non-negative and
+    /// sum to 1.0 (within `SCORE_WEIGHT_TOLERANCE`), and the moving-average
+    /// alphas must lie strictly between 0 and 1.
+    pub fn validate(&self) -> Result<()> {
+        for (name, alpha) in [
+            ("cognitive_load_alpha", self.cognitive_load_alpha),
+            ("response_time_alpha", self.response_time_alpha),
+        ] {
+            if !(alpha > 0.0 && alpha < 1.0) {
+                anyhow::bail!("{} must be within (0, 1), got {}", name, alpha);
+            }
+        }
+        let weights = [self.connectivity_weight, self.strength_weight];
+        if weights.iter().any(|weight| !weight.is_finite() || *weight < 0.0) {
+            anyhow::bail!("Synergy score weights must be non-negative finite numbers, got {:?}", weights);
+        }
+        let sum: f64 = weights.iter().sum();
+        if (sum - 1.0).abs() > SCORE_WEIGHT_TOLERANCE {
+            anyhow::bail!("connectivity_weight and strength_weight must sum to 1.0, got {}", sum);
+        }
+        Ok(())
+    }
+}
+
+/// How far `connectivity_weight + strength_weight` may drift from 1.0
+pub const SCORE_WEIGHT_TOLERANCE: f64 = 1e-6;
+
 /// Reinforcement coordinator for configuration validation and optimization
 pub struct ConfigReinforcement {
     config: HypergraphConfig,
     last_audit: std::time::Instant,
+    audit_interval: Duration,
 }
 
 impl ConfigReinforcement {
     /// Create a new configuration reinforcement instance
     pub fn new(config: HypergraphConfig) -> Self {
+        let audit_interval = Duration::from_secs(config.audit_interval_seconds);
         Self {
             config,
             last_audit: std::time::Instant::now(),
+            audit_interval,
         }
     }
 
+    /// Current interval between scheduled audits
+    pub fn audit_interval(&self) -> Duration {
+        self.audit_interval
+    }
+
+    /// Adjust the audit interval based on the latest audit results
+    ///
+    /// Any Critical or Warning module halves the interval down to
+    /// `min_audit_interval_seconds`; an all-healthy audit doubles it back up
+    /// to `audit_interval_seconds`.
+    pub fn adapt_audit_interval(&mut self, audits: &[ModuleAudit]) -> Duration {
+        let base = Duration::from_secs(self.config.audit_interval_seconds);
+        let floor = Duration::from_secs(self.config.min_audit_interval_seconds).min(base);
+        
+        let needs_attention = audits.iter()
+            .any(|audit| matches!(audit.status, ModuleStatus::Critical | ModuleStatus::Warning));
+        
+        self.audit_interval = if needs_attention {
+            (self.audit_interval / 2).max(floor)
+        } else {
+            (self.audit_interval * 2).min(base)
+        };
+        
+        self.audit_interval
+    }
+
     /// Validate and reinforce core module configurations
     pub async fn validate_configurations(&mut self, global_config: &GlobalConfig) -> Result<Vec<String>> {
         let start_time = std::time::Instant::now();
@@ -55,7 +233,7 @@ impl ConfigReinforcement {
             .context("Failed to record config validation activity")?;
 
         // Check if audit is needed
-        if self.last_audit.elapsed().as_secs() >= self.config.audit_interval_seconds {
+        if self.last_audit.elapsed() >= self.audit_interval {
             recommendations.extend(self.perform_scheduled_audit().await?);
             self.last_audit = std::time::Instant::now();
         }
@@ -77,20 +255,31 @@ impl ConfigReinforcement {
     }
 
     /// Perform scheduled hypergraph audit
-    async fn perform_scheduled_audit(&self) -> Result<Vec<String>> {
+    async fn perform_scheduled_audit(&mut self) -> Result<Vec<String>> {
         let mut recommendations = Vec::new();
 
+        // Unused connections fade once per interval
+        match hypergraph::decay_connections() {
+            Ok(removed) if removed > 0 => log::debug!("Decay removed {} weak connections", removed),
+            Ok(_) => {}
+            Err(err) => recommendations.push(format!("Failed to decay connections: {}", err)),
+        }
+
         match hypergraph::audit_core_modules() {
             Ok(audits) => {
+                self.adapt_audit_interval(&audits);
                 for audit in audits {
-                    if audit.synergy_score < self.config.synergy_threshold {
+                    // Scores backed by few operations are pulled toward the
+                    // threshold so noisy early data doesn't trigger advice
+                    let weighted_score = audit.confidence_weighted_score(self.config.synergy_threshold);
+                    if hypergraph::below_threshold(weighted_score, self.config.synergy_threshold, self.config.comparison_epsilon) {
                         recommendations.push(format!(
                             "Module '{}' has low synergy score ({:.2}). Consider reviewing connections.",
                             audit.module_name, audit.synergy_score
                         ));
                     }
 
-                    if matches!(audit.status, crate::hypergraph::ModuleStatus::Critical) {
+                    if matches!(audit.status, ModuleStatus::Critical) {
                         recommendations.push(format!(
                             "Module '{}' is in critical state. Immediate attention required.",
                             audit.module_name
@@ -107,17 +296,68 @@ impl ConfigReinforcement {
     }
 
     /// Validate critical configuration paths
-    fn validate_critical_paths(&self, _global_config: &GlobalConfig) -> Result<Vec<String>> {
+    ///
+    /// Checks that every client has a known type, a provider name and a
+    /// well-formed `api_base`, that the selected and RAG models name a
+    /// configured provider, and that the sessions directory is usable.
+    pub(crate) fn validate_critical_paths(&self, global_config: &GlobalConfig) -> Result<Vec<String>> {
         let mut recommendations = Vec::new();
+        let config = global_config.read();
+
+        // Client and API endpoint configurations
+        let mut providers = Vec::new();
+        for (index, client) in config.clients.iter().enumerate() {
+            let Some(name) = client.name() else {
+                recommendations.push(format!("Client #{} has an unknown type", index + 1));
+                continue;
+            };
+            if name.trim().is_empty() {
+                recommendations.push(format!("Client #{} has an empty provider name", index + 1));
+                continue;
+            }
+            providers.push(name);
+            if let Some(api_base) = client.api_base() {
+                if let Err(err) = check_endpoint(api_base) {
+                    recommendations.push(format!("Client '{}' has an invalid api_base '{}': {}", name, api_base, err));
+                }
+            }
+        }
+
+        // Model configurations, including the RAG models
+        let models = [
+            ("model", Some(config.model_id.as_str())),
+            ("rag_embedding_model", config.rag_embedding_model.as_deref()),
+            ("rag_reranker_model", config.rag_reranker_model.as_deref()),
+        ];
+        for (setting, model_id) in models {
+            let Some(model_id) = model_id.filter(|id| !id.is_empty()) else {
+                continue;
+            };
+            let provider = model_id.split_once(':').map_or(model_id, |(provider, _)| provider);
+            if provider.trim().is_empty() {
+                recommendations.push(format!("{} '{}' has an empty provider name", setting, model_id));
+            } else if !providers.is_empty() && !providers.contains(&provider) {
+                recommendations.push(format!(
+                    "{} '{}' refers to provider '{}', which is not among the configured clients",
+                    setting, model_id, provider
+                ));
+            }
+        }
 
-        // Basic configuration validation
-        recommendations.push("Configuration paths validated successfully".to_string());
+        // Session management settings
+        let sessions_dir = config.sessions_dir();
+        if sessions_dir.exists() && !sessions_dir.is_dir() {
+            recommendations.push(format!("Sessions path '{}' is not a directory", sessions_dir.display()));
+        } else if !sessions_dir.exists() && sessions_dir.parent().is_some_and(|parent| !parent.is_dir()) {
+            recommendations.push(format!(
+                "Sessions directory '{}' cannot be created because its parent does not exist",
+                sessions_dir.display()
+            ));
+        }
 
-        // TODO: Add specific validation logic for:
-        // - Model configurations
-        // - API endpoint configurations  
-        // - Session management settings
-        // - RAG configurations
+        if recommendations.is_empty() {
+            recommendations.push("Configuration paths validated successfully".to_string());
+        }
 
         Ok(recommendations)
     }
@@ -128,27 +368,7 @@ impl ConfigReinforcement {
 
         // Get current performance metrics
         match hypergraph::get_hypergraph_coordinator() {
-            Ok(coordinator) => {
-                let metrics = coordinator.get_performance_metrics();
-                
-                if metrics.total_operations > 0 {
-                    let error_rate = (metrics.total_operations as f64) * 0.1; // Estimate error rate
-                    
-                    if error_rate > self.config.max_module_errors as f64 {
-                        recommendations.push(format!(
-                            "High error rate detected ({:.1}). Consider implementing circuit breakers.",
-                            error_rate
-                        ));
-                    }
-                }
-
-                if metrics.synergy_coefficient < self.config.synergy_threshold {
-                    recommendations.push(format!(
-                        "System synergy coefficient ({:.2}) below threshold ({:.2}). Review module connections.",
-                        metrics.synergy_coefficient, self.config.synergy_threshold
-                    ));
-                }
-            }
+            Ok(coordinator) => recommendations.extend(self.error_threshold_recommendations(&coordinator)),
             Err(err) => {
                 recommendations.push(format!("Unable to access hypergraph coordinator: {}", err));
             }
@@ -157,6 +377,32 @@ impl ConfigReinforcement {
         Ok(recommendations)
     }
 
+    /// Compare `coordinator`'s error rate and synergy coefficient against their thresholds
+    ///
+    /// The error rate is the errors recorded by all modules divided by the
+    /// total operations.
+    pub(crate) fn error_threshold_recommendations(&self, coordinator: &HypergraphCoordinator) -> Vec<String> {
+        let mut recommendations = Vec::new();
+        let metrics = coordinator.get_performance_metrics();
+        
+        if metrics.total_operations > 0
+            && hypergraph::above_threshold(metrics.error_rate, self.config.max_error_rate, self.config.comparison_epsilon)
+        {
+            recommendations.push(format!(
+                "High error rate detected ({:.1}% of operations, threshold {:.1}%). Consider implementing circuit breakers.",
+                metrics.error_rate * 100.0, self.config.max_error_rate * 100.0
+            ));
+        }
+
+        if hypergraph::below_threshold(metrics.synergy_coefficient, self.config.synergy_threshold, self.config.comparison_epsilon) {
+            recommendations.push(format!(
+                "System synergy coefficient ({:.2}) below threshold ({:.2}). Review module connections.",
+                metrics.synergy_coefficient, self.config.synergy_threshold
+            ));
+        }
+        recommendations
+    }
+
     /// Assess connection health and recommend improvements
     async fn assess_connection_health(&self) -> Result<Vec<String>> {
         let mut recommendations = Vec::new();
@@ -164,54 +410,219 @@ impl ConfigReinforcement {
         // Connection health assessment
         recommendations.push("Connection health assessment completed".to_string());
 
+        let coordinator = hypergraph::get_hypergraph_coordinator()?;
+        let components = coordinator.connected_components();
+        if components.len() > 1 {
+            recommendations.push(format!(
+                "Module topology is split into {} disconnected groups; isolated modules cannot share cognitive memory",
+                components.len()
+            ));
+        }
+
+        for (module, connections) in coordinator.over_connected_modules() {
+            recommendations.push(format!(
+                "Module '{}' has {} connections; consider load balancing its responsibilities",
+                module, connections
+            ));
+        }
+
         // TODO: Implement detailed connection analysis:
-        // - Check for isolated modules
         // - Analyze connection strength distribution
         // - Recommend new connections for better synergy
-        // - Identify over-connected modules that may need load balancing
 
         Ok(recommendations)
     }
 
     /// Apply auto-healing measures if enabled
-    pub async fn apply_auto_healing(&self) -> Result<Vec<String>> {
-        let mut healing_actions = Vec::new();
+    pub async fn apply_auto_healing(&self) -> Result<Vec<HealingOutcome>> {
+        let coordinator = hypergraph::get_hypergraph_coordinator()?;
+        self.apply_auto_healing_to(&coordinator).await
+    }
+
+    /// Preview the actions auto-healing would take, without applying them
+    pub async fn plan_auto_healing(&self) -> Result<Vec<HealingAction>> {
+        let coordinator = hypergraph::get_hypergraph_coordinator()?;
+        self.plan_auto_healing_to(&coordinator).await
+    }
+
+    /// Apply auto-healing measures to `coordinator` if enabled
+    ///
+    /// Executes exactly the actions `plan_auto_healing_to` returns. Every
+    /// attempted action is logged and returned with its outcome, so automated
+    /// changes can be audited afterwards.
+    pub async fn apply_auto_healing_to(&self, coordinator: &HypergraphCoordinator) -> Result<Vec<HealingOutcome>> {
+        let actions = self.plan_auto_healing_to(coordinator).await?;
+        Ok(self.apply_healing_actions_to(coordinator, actions))
+    }
 
-        if !self.config.enable_auto_healing {
-            return Ok(healing_actions);
+    /// Apply a chosen subset of planned actions to `coordinator`
+    ///
+    /// Actions are applied in order and a failure does not stop the rest.
+    pub fn apply_healing_actions_to(&self, coordinator: &HypergraphCoordinator, actions: Vec<HealingAction>) -> Vec<HealingOutcome> {
+        actions.into_iter()
+            .map(|action| apply_healing_action(coordinator, action))
+            .collect()
+    }
+
+    /// Actions auto-healing would take on `coordinator`, without applying them
+    ///
+    /// Disconnected modules are reconnected to the healing hub, and
+    /// connections weaker than `synergy_threshold` between two recently active
+    /// modules are strengthened by `healing_strength_increment`. Per-module
+    /// overrides set with `set_auto_healing` take precedence over
+    /// `enable_auto_healing`. Planning runs an audit, so it is recorded in the
+    /// audit history like any other, unless healing is disabled everywhere.
+    pub async fn plan_auto_healing_to(&self, coordinator: &HypergraphCoordinator) -> Result<Vec<HealingAction>> {
+        let mut actions = Vec::new();
+        if !self.config.enable_auto_healing && !coordinator.has_auto_healing_opt_in() {
+            return Ok(actions);
         }
 
-        // Auto-healing logic
-        match hypergraph::audit_core_modules() {
-            Ok(audits) => {
-                for audit in audits {
-                    if matches!(audit.status, crate::hypergraph::ModuleStatus::Disconnected) {
-                        // Attempt to reconnect disconnected modules
-                        if let Err(err) = hypergraph::establish_connection(
-                            &audit.module_name,
-                            "config", // Connect to config as a hub
-                            0.5, // Lower initial strength
-                        ) {
-                            healing_actions.push(format!(
-                                "Failed to reconnect module '{}': {}",
-                                audit.module_name, err
-                            ));
-                        } else {
-                            healing_actions.push(format!(
-                                "Reconnected disconnected module '{}'",
-                                audit.module_name
-                            ));
-                        }
-                    }
-                }
+        let audits = coordinator.audit_core_modules()
+            .context("Auto-healing audit failed")?;
+        let hub = healing_hub(coordinator, &audits, &self.config);
+        let enabled = |module: &str| coordinator.auto_healing_override(module)
+            .unwrap_or(self.config.enable_auto_healing);
+        let connections = coordinator.top_connections(usize::MAX);
+        for audit in &audits {
+            if !enabled(&audit.module_name) {
+                log::debug!("Auto-healing disabled for module '{}'", audit.module_name);
+                continue;
             }
-            Err(err) => {
-                healing_actions.push(format!("Auto-healing audit failed: {}", err));
+            if matches!(audit.status, ModuleStatus::Disconnected) {
+                // Attempt to reconnect disconnected modules to the hub,
+                // with a lower initial strength
+                actions.push(HealingAction::Reconnect {
+                    module: audit.module_name.clone(),
+                    hub: hub.clone(),
+                    strength: 0.5,
+                });
             }
         }
 
-        Ok(healing_actions)
+        let increment = self.config.healing_strength_increment;
+        if increment > 0.0 {
+            for (module_a, module_b, strength) in connections {
+                let weak = hypergraph::below_threshold(strength, self.config.synergy_threshold, self.config.comparison_epsilon);
+                // Only reinforce pairs that are still talking to each other
+                if !weak || !enabled(&module_a) || !enabled(&module_b)
+                    || !coordinator.is_recently_active(&module_a) || !coordinator.is_recently_active(&module_b) {
+                    continue;
+                }
+                actions.push(HealingAction::Strengthen {
+                    from: strength,
+                    to: (strength + increment).min(1.0),
+                    module_a,
+                    module_b,
+                });
+            }
+        }
+
+        Ok(actions)
+    }
+}
+
+/// Apply one healing action, logging it and recording a `Healing` event
+fn apply_healing_action(coordinator: &HypergraphCoordinator, action: HealingAction) -> HealingOutcome {
+    let (module, result) = match &action {
+        HealingAction::Reconnect { module, hub, strength } => {
+            (module, coordinator.establish_connection(module, hub, *strength))
+        }
+        HealingAction::Strengthen { module_a, module_b, from, to } => {
+            (module_a, strengthen_directions(coordinator, module_a, module_b, to - from))
+        }
+    };
+    let result = result.map_err(|err| err.to_string());
+    match &result {
+        Ok(()) => log::info!("Auto-healing: {} succeeded", action),
+        Err(err) => log::warn!("Auto-healing: {} failed: {}", action, err),
+    }
+    coordinator.push_event(CoordinatorEvent::Healing {
+        module: module.clone(),
+        action: action.to_string(),
+        error: result.clone().err(),
+    });
+    HealingOutcome { action, result }
+}
+
+/// Raise each existing direction between two modules by `increment`
+///
+/// Directions that were never set stay unset, so directed edges stay
+/// directed and asymmetric pairs keep their asymmetry.
+fn strengthen_directions(coordinator: &HypergraphCoordinator, module_a: &str, module_b: &str, increment: f64) -> Result<()> {
+    let existing: Vec<(&str, &str, f64)> = {
+        let synergy_matrix = coordinator.synergy_matrix.read();
+        [(module_a, module_b), (module_b, module_a)].into_iter()
+            .filter_map(|(from, to)| {
+                synergy_matrix.get(&(from.to_string(), to.to_string())).map(|strength| (from, to, *strength))
+            })
+            .collect()
+    };
+    if existing.is_empty() {
+        anyhow::bail!("No connection between '{}' and '{}'", module_a, module_b);
+    }
+    for (from, to, strength) in existing {
+        coordinator.establish_directed_connection(from, to, (strength + increment).min(1.0))?;
     }
+    Ok(())
+}
+
+/// Check that `api_base` looks like a reachable HTTP(S) endpoint
+fn check_endpoint(api_base: &str) -> std::result::Result<(), String> {
+    let url = reqwest::Url::parse(api_base).map_err(|err| err.to_string())?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(format!("unsupported scheme '{}'", url.scheme()));
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err("missing host".to_string());
+    }
+    Ok(())
+}
+
+/// Module disconnected modules are reconnected to during auto-healing
+///
+/// The most central healthy module is preferred; ties go to the earliest
+/// registered. Without a connected healthy module the first infrastructure
+/// hub is used.
+fn healing_hub(coordinator: &HypergraphCoordinator, audits: &[ModuleAudit], config: &HypergraphConfig) -> String {
+    let healthy = |name: &str| audits.iter()
+        .any(|audit| audit.module_name == name && matches!(audit.status, ModuleStatus::Healthy));
+    coordinator.centrality_ranking()
+        .into_iter()
+        .find(|(name, score)| *score > 0.0 && healthy(name))
+        .map(|(name, _)| name)
+        .or_else(|| config.infrastructure_hubs.first().cloned())
+        .unwrap_or_else(|| "config".to_string())
+}
+
+/// Corrective change attempted by auto-healing
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealingAction {
+    /// Connect a disconnected module to a hub module
+    Reconnect { module: String, hub: String, strength: f64 },
+    /// Raise each existing direction of a weak connection between two active
+    /// modules by `to - from`, where `from` is the stronger direction
+    Strengthen { module_a: String, module_b: String, from: f64, to: f64 },
+}
+
+impl std::fmt::Display for HealingAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealingAction::Reconnect { module, hub, strength } => {
+                write!(f, "reconnect '{}' to '{}' (strength: {:.2})", module, hub, strength)
+            }
+            HealingAction::Strengthen { module_a, module_b, from, to } => {
+                write!(f, "strengthen '{}' <-> '{}' from {:.2} to {:.2}", module_a, module_b, from, to)
+            }
+        }
+    }
+}
+
+/// An auto-healing action together with whether it was applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealingOutcome {
+    pub action: HealingAction,
+    pub result: std::result::Result<(), String>,
 }
 
 /// Global configuration reinforcement instance
@@ -219,7 +630,9 @@ static CONFIG_REINFORCEMENT: once_cell::sync::OnceCell<std::sync::Arc<tokio::syn
 
 /// Initialize global configuration reinforcement
 pub fn init_config_reinforcement(config: Option<HypergraphConfig>) -> Result<()> {
-    let reinforcement = ConfigReinforcement::new(config.unwrap_or_default());
+    let config = config.unwrap_or_default();
+    config.validate()?;
+    let reinforcement = ConfigReinforcement::new(config);
     let reinforcement = std::sync::Arc::new(tokio::sync::Mutex::new(reinforcement));
     
     CONFIG_REINFORCEMENT.set(reinforcement)
@@ -243,7 +656,7 @@ pub async fn validate_configurations(global_config: &GlobalConfig) -> Result<Vec
 }
 
 /// Convenience function to apply auto-healing
-pub async fn apply_auto_healing() -> Result<Vec<String>> {
+pub async fn apply_auto_healing() -> Result<Vec<HealingOutcome>> {
     let reinforcement = get_config_reinforcement().await?;
     let reinforcement = reinforcement.lock().await;
     reinforcement.apply_auto_healing().await
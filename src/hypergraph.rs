@@ -5,166 +5,3121 @@
 //! cognitive coherence validation.
 
 use crate::config::GlobalConfig;
+use crate::config_reinforcement::{ConfigReinforcement, HypergraphConfig};
 use anyhow::{anyhow, Result};
 use indexmap::IndexMap;
+use nu_ansi_term::{Color, Style};
 use parking_lot::RwLock;
-use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+/// Width of a single bucket in the rolling metrics window
+const METRICS_BUCKET_WIDTH: Duration = Duration::from_secs(1);
+
+/// How far back rolling metrics buckets are retained
+const METRICS_RETENTION: Duration = Duration::from_secs(3600);
+
+/// Decimal places floats are rounded to in stable JSON output
+const STABLE_JSON_PRECISION: i32 = 6;
+
+/// Default tolerance for comparing synergy scores and connection strengths
+pub const DEFAULT_EPSILON: f64 = 1e-9;
+
+/// Cognitive load weight of operation types missing from `load_factors`
+pub const DEFAULT_LOAD_FACTOR: f64 = 0.4;
+
+/// Recent error messages kept per module
+const RECENT_ERRORS_CAPACITY: usize = 50;
+
+/// Status events buffered per subscriber before the slowest one starts lagging
+const STATUS_EVENT_CAPACITY: usize = 64;
+
+/// Module that RAG retrieval quality is attributed to
+const RAG_MODULE: &str = "rag";
+
+/// Number of entries in each ranked list of the dashboard
+const DASHBOARD_TOP_N: usize = 5;
+
+/// Trailing window the dashboard's throughput is measured over
+const DASHBOARD_THROUGHPUT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Number of recent retrievals the retrieval quality metric is computed over
+const RETRIEVAL_QUALITY_WINDOW: usize = 50;
+
+/// Message count at which audit confidence reaches 0.5
+const CONFIDENCE_HALF_POINT: f64 = 30.0;
+
+/// Source of monotonic time for the coordinator
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// Clock backed by `Instant::now()`
+#[derive(Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Clock that only moves when explicitly advanced, for deterministic tests
+#[derive(Debug)]
+pub struct ManualClock {
+    base: Instant,
+    offset: RwLock<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self {
+            base: Instant::now(),
+            offset: RwLock::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.offset.write() += duration;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.base + *self.offset.read()
+    }
+}
+
 /// Represents the health and synergy status of core modules
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModuleAudit {
     pub module_name: String,
     pub status: ModuleStatus,
     pub synergy_score: f64,
+    /// Trust in `synergy_score` given the sample size, in `[0, 1)`
+    pub confidence: f64,
     pub hypergraph_connections: usize,
+    /// Serialized as seconds since the Unix epoch, assuming the system clock
+    /// (see `instant_as_unix_seconds`)
+    #[serde(with = "instant_as_unix_seconds")]
     pub last_checked: Instant,
     pub issues: Vec<String>,
     pub recommendations: Vec<String>,
+    /// Custom gauges set on the module via `set_gauge`
+    #[serde(default)]
+    pub gauges: IndexMap<String, f64>,
+}
+
+/// Serde adapter mapping an `Instant` to wall-clock seconds since the Unix epoch
+///
+/// `Instant` has no fixed origin, so the conversion goes through the offset
+/// from the current instant and is only as exact as the two clock reads.
+/// It always reads the system clocks, not the coordinator's `Clock`: audits
+/// taken under a `ManualClock` serialize to meaningless wall-clock times.
+mod instant_as_unix_seconds {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        let (now, wall_now) = (Instant::now(), SystemTime::now());
+        let wall = if *instant <= now {
+            wall_now - (now - *instant)
+        } else {
+            wall_now + (*instant - now)
+        };
+        let seconds = wall.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs_f64();
+        seconds.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let seconds = f64::deserialize(deserializer)?;
+        let wall = Duration::try_from_secs_f64(seconds)
+            .map(|since_epoch| UNIX_EPOCH + since_epoch)
+            .map_err(serde::de::Error::custom)?;
+        let (now, wall_now) = (Instant::now(), SystemTime::now());
+        Ok(match wall_now.duration_since(wall) {
+            Ok(age) => now.checked_sub(age).unwrap_or(now),
+            Err(err) => now + err.duration(),
+        })
+    }
+}
+
+impl ModuleAudit {
+    /// Synergy score pulled toward `neutral` in proportion to how little data backs it
+    pub fn confidence_weighted_score(&self, neutral: f64) -> f64 {
+        neutral + (self.synergy_score - neutral) * self.confidence
+    }
+}
+
+/// Saturating confidence for a score computed from `message_count` samples
+fn sample_confidence(message_count: u64) -> f64 {
+    let samples = message_count as f64;
+    samples / (samples + CONFIDENCE_HALF_POINT)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModuleStatus {
+    Healthy,
+    Warning,
+    Critical,
+    Disconnected,
+}
+
+/// Request accepted by `HypergraphCoordinator::handle_command`
+///
+/// Serialized as `{"method": "...", "params": {...}}` so an external
+/// supervisor can drive the coordinator over a socket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum CoordinatorCommand {
+    Register { module: String },
+    Connect { module_a: String, module_b: String, strength: f64 },
+    RecordActivity { module: String, operation_type: String, duration_ms: u64 },
+    RecordError { module: String, error: String },
+    Audit,
+    Report,
+}
+
+/// Response produced for a `CoordinatorCommand`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum CommandResponse {
+    Ok,
+    Audit { modules: Vec<AuditSummary> },
+    Report { report: String },
+    Error { message: String },
+}
+
+/// How central a module is to the hypergraph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuleCentrality {
+    pub module_name: String,
+    /// Number of connected peers
+    pub degree: usize,
+    /// Degree normalized by the number of other modules, in `[0, 1]`
+    pub score: f64,
+    /// Whether the module is a designated infrastructure hub such as `config`
+    pub infrastructure: bool,
+}
+
+/// Significant coordinator event kept in the recent-events ring buffer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum CoordinatorEvent {
+    /// A module's audit status changed; `from` is `None` on its first audit
+    StatusChanged { module: String, from: Option<ModuleStatus>, to: ModuleStatus },
+    /// Auto-healing attempted to reconnect a module
+    Healing { module: String, action: String, error: Option<String> },
+}
+
+/// An operation in progress, started with `HypergraphCoordinator::begin_operation`
+///
+/// Dropping the guard ends the operation and records its elapsed time as
+/// activity of the operation type it was started with.
+#[must_use = "the operation ends as soon as the guard is dropped"]
+pub struct OperationGuard<'a> {
+    coordinator: &'a HypergraphCoordinator,
+    module: String,
+    operation_type: String,
+    id: u64,
+    started: Instant,
+}
+
+impl Drop for OperationGuard<'_> {
+    fn drop(&mut self) {
+        let coordinator = self.coordinator;
+        if let Some(operations) = coordinator.in_flight.write().get_mut(&self.module) {
+            operations.remove(&self.id);
+        }
+        let elapsed = coordinator.now().duration_since(self.started);
+        if let Err(err) = coordinator.record_activity(&self.module, &self.operation_type, elapsed) {
+            log::warn!("Failed to record operation for module '{}': {}", self.module, err);
+        }
+    }
+}
+
+/// A module's status changed between two consecutive audits
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleStatusEvent {
+    pub module: String,
+    pub old: ModuleStatus,
+    pub new: ModuleStatus,
+    pub at: Instant,
+}
+
+/// A coordinator mutation captured by the activity recorder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Wall-clock time of the event in milliseconds since the Unix epoch
+    pub at_ms: u64,
+    #[serde(flatten)]
+    pub kind: RecordedEventKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum RecordedEventKind {
+    RegisterModule { module: String },
+    /// Older recordings lack `directed` and describe symmetric connections
+    Connect {
+        module_a: String,
+        module_b: String,
+        strength: f64,
+        #[serde(default)]
+        directed: bool,
+    },
+    Activity { module: String, operation: String, duration_us: u64 },
+    Error { module: String, message: String },
+    UnregisterModule { module: String },
+    /// Follows the `Connect` event of a connection made with a TTL
+    ConnectionTtl { module_a: String, module_b: String, ttl_ms: u64 },
+    /// Follows the `Connect` event of a connection made with an idle TTL
+    ConnectionIdleTtl { module_a: String, module_b: String, idle_ttl_ms: u64 },
+}
+
+/// Read every event from a recording written by `start_recording`
+pub fn read_recording(path: &std::path::Path) -> Result<Vec<RecordedEvent>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| anyhow!("Failed to read recording '{}': {}", path.display(), err))?;
+    content.lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|err| anyhow!("Invalid event on line {} of '{}': {}", index + 1, path.display(), err))
+        })
+        .collect()
+}
+
+/// Open recording: events are timestamped relative to when it started
+struct ActivityRecorder {
+    writer: std::io::LineWriter<std::fs::File>,
+    started_at: Instant,
+    started_wall_ms: u64,
+}
+
+/// Aggregate shape of the synergy matrix at one audit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MatrixStats {
+    /// Number of undirected edges
+    pub edges: usize,
+    /// Mean strength across edges, 0.0 without edges
+    pub mean_strength: f64,
+    /// Edges relative to the maximum possible between registered modules
+    pub density: f64,
+}
+
+/// Number of modules in each audit status
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusCounts {
+    pub healthy: usize,
+    pub warning: usize,
+    pub critical: usize,
+    pub disconnected: usize,
+}
+
+impl StatusCounts {
+    /// Tally the statuses of `audits`
+    pub fn from_audits<'a>(audits: impl IntoIterator<Item = &'a ModuleAudit>) -> Self {
+        let mut counts = Self::default();
+        for audit in audits {
+            match audit.status {
+                ModuleStatus::Healthy => counts.healthy += 1,
+                ModuleStatus::Warning => counts.warning += 1,
+                ModuleStatus::Critical => counts.critical += 1,
+                ModuleStatus::Disconnected => counts.disconnected += 1,
+            }
+        }
+        counts
+    }
+}
+
+/// Performance section of a `HealthReport`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportMetrics {
+    pub total_operations: u64,
+    pub average_response_time_ms: f64,
+    pub memory_efficiency: f64,
+    pub memory_efficiency_trend: TrendDirection,
+    #[serde(default)]
+    pub cognitive_efficiency: f64,
+    pub synergy_coefficient: f64,
+    pub error_rate: f64,
+}
+
+/// Structured health report; the text report is rendered from this
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub features: FeatureFlags,
+    pub metrics: ReportMetrics,
+    pub status_counts: StatusCounts,
+    /// Sub-steps of the audit that failed without aborting it
+    pub audit_errors: Vec<String>,
+    /// Latency statistics per operation type, sorted by operation type
+    #[serde(default)]
+    pub operation_latencies: IndexMap<String, LatencyStats>,
+    /// Per-module audits in the configured report order
+    pub modules: Vec<AuditSummary>,
+}
+
+/// Everything a landing page needs, built around a single audit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dashboard {
+    /// Mean per-module health in `[0, 1]`: Healthy 1, Warning 0.5, Disconnected 0.25, Critical 0
+    pub health_score: f64,
+    pub status_counts: StatusCounts,
+    /// Best-connected modules with their connection counts
+    pub top_hubs: Vec<(String, usize)>,
+    /// Modules with the largest attention allocation
+    pub top_struggling: Vec<(String, f64)>,
+    pub synergy_coefficient: f64,
+    /// Operations per second over the last minute
+    pub throughput: f64,
+}
+
+/// Wire-friendly view of a `ModuleAudit`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditSummary {
+    pub module_name: String,
+    pub status: ModuleStatus,
+    pub synergy_score: f64,
+    pub confidence: f64,
+    pub hypergraph_connections: usize,
+    pub issues: Vec<String>,
+    pub recommendations: Vec<String>,
+    #[serde(default)]
+    pub gauges: IndexMap<String, f64>,
+}
+
+impl From<&ModuleAudit> for AuditSummary {
+    fn from(audit: &ModuleAudit) -> Self {
+        Self {
+            module_name: audit.module_name.clone(),
+            status: audit.status.clone(),
+            synergy_score: audit.synergy_score,
+            confidence: audit.confidence,
+            hypergraph_connections: audit.hypergraph_connections,
+            issues: audit.issues.clone(),
+            recommendations: audit.recommendations.clone(),
+            gauges: audit.gauges.clone(),
+        }
+    }
+}
+
+/// Intended module topology, typically loaded from a YAML file
+///
+/// ```yaml
+/// connections:
+///   - [client, config]
+///   - [rag, client]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TopologyDeclaration {
+    #[serde(default)]
+    pub connections: Vec<(String, String)>,
+}
+
+impl TopologyDeclaration {
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content).map_err(|err| anyhow!("Invalid topology declaration: {}", err))
+    }
+
+    pub fn load(path: &std::path::Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read topology declaration '{}': {}", path.display(), err))?;
+        Self::from_yaml(&content)
+    }
+}
+
+/// How much of the declared topology is actually established
+///
+/// Edges are undirected and reported with their module names sorted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Coverage {
+    pub expected_edges: usize,
+    /// Declared edges present in the live matrix
+    pub present: Vec<(String, String)>,
+    /// Declared edges absent from the live matrix
+    pub missing: Vec<(String, String)>,
+    /// Live edges that were not declared
+    pub extra: Vec<(String, String)>,
+}
+
+impl Coverage {
+    /// Fraction of declared edges that are present, 1.0 when nothing is declared
+    pub fn ratio(&self) -> f64 {
+        if self.expected_edges == 0 {
+            1.0
+        } else {
+            self.present.len() as f64 / self.expected_edges as f64
+        }
+    }
+}
+
+/// Point-in-time, serializable capture of the coordinator's topology and scores
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HypergraphSnapshot {
+    pub modules: IndexMap<String, ModuleSnapshot>,
+    pub synergy_coefficient: f64,
+}
+
+/// Destination for periodically persisted snapshots
+pub trait SnapshotStore: Send + Sync {
+    fn save(&self, snapshot: &HypergraphSnapshot) -> Result<()>;
+}
+
+/// Snapshot store that keeps every saved snapshot in memory
+#[derive(Debug, Default)]
+pub struct MemorySnapshotStore {
+    snapshots: RwLock<Vec<HypergraphSnapshot>>,
+}
+
+impl MemorySnapshotStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All snapshots saved so far, oldest first
+    pub fn snapshots(&self) -> Vec<HypergraphSnapshot> {
+        self.snapshots.read().clone()
+    }
+}
+
+impl SnapshotStore for MemorySnapshotStore {
+    fn save(&self, snapshot: &HypergraphSnapshot) -> Result<()> {
+        self.snapshots.write().push(snapshot.clone());
+        Ok(())
+    }
+}
+
+/// Current on-disk format written by `save_snapshot`
+const PERSISTED_SCHEMA_VERSION: u32 = 1;
+
+/// Most recent audits carried over in a persisted snapshot
+const PERSISTED_AUDIT_HISTORY: usize = 100;
+
+/// On-disk form of the coordinator state written by `save_snapshot`
+///
+/// Instants are not serializable, so timestamps are dropped on save and
+/// rebased to the load time by `load_snapshot`.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    schema_version: u32,
+    modules: Vec<PersistedModule>,
+    /// Directed synergy matrix entries as `(from, to, strength)`
+    synergy_matrix: Vec<(String, String, f64)>,
+    audit_history: Vec<AuditSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedModule {
+    name: String,
+    active_connections: Vec<String>,
+    message_count: u64,
+    error_count: u64,
+    memory_usage: usize,
+    cognitive_load: f64,
+    declared_operations: Option<Vec<String>>,
+    undeclared_operations: Vec<String>,
+    lifecycle: ModuleLifecycle,
+    gauges: IndexMap<String, f64>,
+}
+
+/// Per-module part of a `HypergraphSnapshot`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleSnapshot {
+    pub message_count: u64,
+    pub error_count: u64,
+    pub synergy_score: f64,
+    pub connections: Vec<String>,
+}
+
+/// A module whose synergy dropped by more than the allowed tolerance
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub module: String,
+    pub baseline_score: f64,
+    pub current_score: f64,
+}
+
+/// Compare two snapshots and list the modules whose synergy regressed
+///
+/// A module regresses when its score drops by more than `tolerance`, or when
+/// it is missing from `current` entirely. Intended for CI gates.
+pub fn assert_no_regression(
+    baseline: &HypergraphSnapshot,
+    current: &HypergraphSnapshot,
+    tolerance: f64,
+) -> std::result::Result<(), Vec<Regression>> {
+    let regressions: Vec<Regression> = baseline.modules.iter()
+        .filter_map(|(module, before)| {
+            let current_score = current.modules.get(module).map(|m| m.synergy_score).unwrap_or(0.0);
+            if before.synergy_score - current_score > tolerance {
+                Some(Regression {
+                    module: module.clone(),
+                    baseline_score: before.synergy_score,
+                    current_score,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+    
+    if regressions.is_empty() {
+        Ok(())
+    } else {
+        Err(regressions)
+    }
+}
+
+/// Optional coordinator behaviors and whether they are currently enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlags {
+    pub decay: bool,
+    pub auto_healing: bool,
+    pub edge_eviction: bool,
+    pub persistence: bool,
+    pub circuit_breakers: bool,
+}
+
+impl std::fmt::Display for FeatureFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+        write!(
+            f,
+            "decay: {}, auto-healing: {}, edge eviction: {}, persistence: {}, circuit breakers: {}",
+            on_off(self.decay),
+            on_off(self.auto_healing),
+            on_off(self.edge_eviction),
+            on_off(self.persistence),
+            on_off(self.circuit_breakers)
+        )
+    }
+}
+
+/// Synthetic failure injected by `inject_failure` for chaos testing
+#[cfg(feature = "chaos")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FailureKind {
+    /// Record this many errors at once
+    BurstErrors(u64),
+    /// Drop every connection of the module
+    Disconnect,
+    /// Record one operation that took this long
+    HighLatency(Duration),
+    /// Force the module's cognitive load to this value
+    LoadSpike(f64),
+}
+
+/// Whether `backfill` adds to or replaces a module's existing counters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackfillMode {
+    Additive,
+    Absolute,
+}
+
+/// Order in which modules are listed in the health report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportOrder {
+    /// Module registration order
+    #[default]
+    Registration,
+    /// Module name, for stable diffs across runs
+    Alphabetical,
+    /// Most severe status first, then module name
+    Severity,
+}
+
+impl ModuleStatus {
+    /// Sort rank where lower means more urgent
+    fn severity_rank(&self) -> u8 {
+        match self {
+            ModuleStatus::Critical => 0,
+            ModuleStatus::Warning => 1,
+            ModuleStatus::Disconnected => 2,
+            ModuleStatus::Healthy => 3,
+        }
+    }
+
+    /// Contribution to the dashboard health score, in `[0, 1]`
+    ///
+    /// A Warning module still does useful work, so it counts for half. A
+    /// Disconnected module may be fine on its own but cannot cooperate, so it
+    /// counts for a quarter. A Critical module counts for nothing.
+    fn health_weight(&self) -> f64 {
+        match self {
+            ModuleStatus::Healthy => 1.0,
+            ModuleStatus::Warning => 0.5,
+            ModuleStatus::Disconnected => 0.25,
+            ModuleStatus::Critical => 0.0,
+        }
+    }
+}
+
+/// What `record_activity` does when a module records an operation type it never declared
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UndeclaredOperationPolicy {
+    /// Log a warning and surface the operation type in the audit
+    #[default]
+    Warn,
+    /// Reject the activity with an error
+    Error,
+}
+
+/// What `record_activity` does when the module was never registered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnknownModulePolicy {
+    /// Count the operation globally but otherwise drop it
+    #[default]
+    Ignore,
+    /// Reject the activity with an error
+    Strict,
+    /// Register the module on first activity
+    AutoRegister,
+}
+
+/// How synergy scoring treats NaN or infinite edge strengths
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NonFiniteStrengthPolicy {
+    /// Count the edge with a strength of 0.0
+    #[default]
+    TreatAsZero,
+    /// Leave the edge out of the strength average
+    Skip,
+}
+
+/// How `merge_from` combines a connection strength present in both coordinators
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MergeStrengthPolicy {
+    /// Keep the stronger of the two
+    #[default]
+    Max,
+    /// Use the mean over every coordinator merged so far
+    Average,
+}
+
+/// Operational phase of a module, set by the module's owner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModuleLifecycle {
+    Starting,
+    Running,
+    /// Finishing in-flight work and not accepting new work
+    Draining,
+    Stopped,
+}
+
+/// State of a module's circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls flow normally
+    Closed,
+    /// Tripped by consecutive errors; calls should be skipped
+    Open { since: Instant },
+    /// Cooldown elapsed and a single trial call has been let through
+    HalfOpen,
+}
+
+/// Per-module breaker bookkeeping
+#[derive(Debug, Clone, Copy)]
+struct CircuitBreaker {
+    state: CircuitState,
+    consecutive_errors: u64,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_errors: 0,
+        }
+    }
+}
+
+/// Whether a module should be sent new work, combining lifecycle and health
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Readiness {
+    /// Running and healthy
+    Ready,
+    /// Running but Warning or Disconnected; usable if nothing better exists
+    Degraded,
+    /// Starting, draining, or Critical
+    NotReady,
+    /// Stopped; should be left out of routing entirely
+    Excluded,
+}
+
+/// Position in the coordinator's change history, returned by `export_changed_since`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ExportMarker(pub u64);
+
+/// Rolling quality of recent RAG retrievals
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetrievalQuality {
+    /// Number of retrievals the averages are computed over
+    pub samples: usize,
+    pub average_top_score: f64,
+    pub average_hits: f64,
+    /// Fraction of retrievals that returned nothing
+    pub empty_rate: f64,
+}
+
+/// How edge strengths are rescaled by `normalize_strengths`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizationMode {
+    /// Divide by the strongest edge so the maximum becomes 1.0
+    MaxScale,
+    /// Standard score: `(strength - mean) / stddev`
+    ZScore,
+}
+
+/// Manages hypergraph synergy across all core modules
+pub struct HypergraphCoordinator {
+    config: GlobalConfig,
+    settings: HypergraphConfig,
+    clock: Arc<dyn Clock>,
+    pub(crate) module_registry: RwLock<IndexMap<String, ModuleMetrics>>,
+    pub(crate) synergy_matrix: RwLock<HashMap<(String, String), f64>>,
+    edge_metadata: RwLock<HashMap<(String, String), EdgeMetadata>>,
+    pub(crate) audit_history: RwLock<Vec<ModuleAudit>>,
+    performance_metrics: RwLock<PerformanceMetrics>,
+    metrics_window: RwLock<VecDeque<MetricsBucket>>,
+    operation_times: RwLock<HashMap<String, Duration>>,
+    performance_series: RwLock<PerformanceSeries>,
+    staleness_windows: RwLock<HashMap<String, Duration>>,
+    memory_samplers: RwLock<HashMap<String, MemorySampler>>,
+    last_statuses: RwLock<HashMap<String, ModuleStatus>>,
+    topology_frozen: AtomicBool,
+    change_counter: AtomicU64,
+    retrievals: RwLock<VecDeque<(f64, usize)>>,
+    audit_cache: RwLock<Option<(Instant, Arc<Vec<ModuleAudit>>)>>,
+    module_weights: RwLock<HashMap<String, f64>>,
+    snapshot_store: Option<Arc<dyn SnapshotStore>>,
+    last_save: RwLock<Option<Instant>>,
+    audit_errors: RwLock<Vec<String>>,
+    dependencies: RwLock<HashMap<String, Vec<String>>>,
+    latency_histograms: RwLock<HashMap<String, LatencyHistogram>>,
+    expected_topology: RwLock<Option<TopologyDeclaration>>,
+    matrix_evolution: RwLock<VecDeque<MatrixStats>>,
+    recorder: parking_lot::Mutex<Option<ActivityRecorder>>,
+    auto_healing_overrides: RwLock<HashMap<String, bool>>,
+    events: RwLock<VecDeque<CoordinatorEvent>>,
+    op_aliases: RwLock<HashMap<String, String>>,
+    /// Broadcasts status transitions detected by audits
+    status_events: tokio::sync::broadcast::Sender<ModuleStatusEvent>,
+    /// Circuit breakers of modules that have recorded errors
+    circuit_breakers: RwLock<HashMap<String, CircuitBreaker>>,
+    operation_latencies: RwLock<HashMap<String, LatencyTracker>>,
+    in_flight: RwLock<HashMap<String, HashMap<u64, Instant>>>,
+    next_operation_id: AtomicU64,
+    /// Coordinators averaged into each merged strength; absent means one
+    merged_samples: RwLock<HashMap<(String, String), u64>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleMetrics {
+    pub(crate) name: String,
+    pub(crate) active_connections: HashSet<String>,
+    pub(crate) message_count: u64,
+    pub(crate) error_count: u64,
+    pub(crate) last_activity: Instant,
+    pub(crate) memory_usage: usize,
+    pub(crate) cognitive_load: f64,
+    /// Operation types the module declared at registration, if any
+    pub(crate) declared_operations: Option<HashSet<String>>,
+    /// Undeclared operation types the module has recorded anyway
+    pub(crate) undeclared_operations: HashSet<String>,
+    pub(crate) lifecycle: ModuleLifecycle,
+    /// Module-specific metrics such as queue depth or cache hit rate
+    pub(crate) gauges: IndexMap<String, f64>,
+    /// Most recent error messages, oldest first
+    pub(crate) recent_errors: VecDeque<(Instant, String)>,
+    /// Value of the coordinator's change counter at this module's last mutation
+    pub(crate) version: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceMetrics {
+    pub total_operations: u64,
+    pub average_response_time: Duration,
+    pub memory_efficiency: f64,
+    /// Memory efficiency discounted by average cognitive load, see `cognitive_efficiency`
+    pub cognitive_efficiency: f64,
+    pub synergy_coefficient: f64,
+    pub error_rate: f64,
+}
+
+/// User-supplied callback reporting a module's current memory footprint in bytes
+pub type MemorySampler = Arc<dyn Fn() -> usize + Send + Sync>;
+
+/// Run a user callback, converting a panic into a logged error
+///
+/// Callbacks are always invoked without any coordinator lock held, so a panic
+/// cannot leave shared state half-updated; this guard additionally keeps the
+/// panic from unwinding through the caller and taking instrumentation down.
+fn guard_callback<T>(name: &str, callback: impl FnOnce() -> T) -> Result<T> {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(callback)) {
+        Ok(value) => Ok(value),
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            log::error!("Callback '{}' panicked: {}", name, message);
+            Err(anyhow!("Callback '{}' panicked: {}", name, message))
+        }
+    }
+}
+
+/// Upper bounds, in seconds, of the module latency histogram buckets
+pub(crate) const LATENCY_BUCKETS_SECONDS: [f64; 11] =
+    [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Latency distribution of a module's activity
+///
+/// Bucket counts are not cumulative; the last bucket holds observations above
+/// every bound. Each bucket keeps the most recent traced observation as its
+/// exemplar.
+#[derive(Debug, Clone)]
+pub(crate) struct LatencyHistogram {
+    pub(crate) counts: [u64; LATENCY_BUCKETS_SECONDS.len() + 1],
+    pub(crate) sum: f64,
+    pub(crate) exemplars: [Option<(String, f64)>; LATENCY_BUCKETS_SECONDS.len() + 1],
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            counts: [0; LATENCY_BUCKETS_SECONDS.len() + 1],
+            sum: 0.0,
+            exemplars: Default::default(),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, duration: Duration, trace_id: Option<&str>) {
+        let seconds = duration.as_secs_f64();
+        let index = LATENCY_BUCKETS_SECONDS.iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_SECONDS.len());
+        self.counts[index] += 1;
+        self.sum += seconds;
+        if let Some(trace_id) = trace_id {
+            self.exemplars[index] = Some((trace_id.to_string(), seconds));
+        }
+    }
+}
+
+/// Sub-buckets per doubling of latency in `LatencyTracker`, about 19% resolution
+const LATENCY_SUB_BUCKETS: f64 = 4.0;
+
+/// Latency summary of one operation type, in milliseconds
+///
+/// Percentiles are estimated from log-scale buckets, so they are accurate to
+/// within one bucket width (about 19%) and always lie within `[min, max]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LatencyStats {
+    pub count: u64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+}
+
+/// Log-bucketed latency distribution backing `LatencyStats`
+#[derive(Debug, Clone, Default)]
+struct LatencyTracker {
+    count: u64,
+    min: Duration,
+    max: Duration,
+    /// Bucket `i` counts latencies up to `2^(i / LATENCY_SUB_BUCKETS)` microseconds
+    buckets: Vec<u64>,
 }
 
-#[derive(Debug, Clone)]
-pub enum ModuleStatus {
-    Healthy,
-    Warning,
-    Critical,
-    Disconnected,
-}
+impl LatencyTracker {
+    fn observe(&mut self, duration: Duration) {
+        if self.count == 0 || duration < self.min {
+            self.min = duration;
+        }
+        self.max = self.max.max(duration);
+        self.count += 1;
+        
+        let micros = duration.as_secs_f64() * 1e6;
+        let index = if micros <= 1.0 { 0 } else { (micros.log2() * LATENCY_SUB_BUCKETS).ceil() as usize };
+        if self.buckets.len() <= index {
+            self.buckets.resize(index + 1, 0);
+        }
+        self.buckets[index] += 1;
+    }
+
+    fn quantile_ms(&self, quantile: f64) -> f64 {
+        let rank = ((quantile * self.count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        let index = self.buckets.iter()
+            .position(|count| {
+                seen += count;
+                seen >= rank
+            })
+            .unwrap_or(self.buckets.len().saturating_sub(1));
+        let upper_ms = 2f64.powf(index as f64 / LATENCY_SUB_BUCKETS) / 1000.0;
+        upper_ms.clamp(self.min.as_secs_f64() * 1000.0, self.max.as_secs_f64() * 1000.0)
+    }
+
+    fn stats(&self) -> LatencyStats {
+        LatencyStats {
+            count: self.count,
+            min_ms: self.min.as_secs_f64() * 1000.0,
+            max_ms: self.max.as_secs_f64() * 1000.0,
+            p50_ms: self.quantile_ms(0.5),
+            p95_ms: self.quantile_ms(0.95),
+        }
+    }
+}
+
+/// Trailing samples of `PerformanceMetrics`, one per audit, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceSeries {
+    pub timestamps: Vec<Instant>,
+    pub average_response_time: Vec<Duration>,
+    pub memory_efficiency: Vec<f64>,
+    pub synergy_coefficient: Vec<f64>,
+}
+
+impl PerformanceSeries {
+    fn push(&mut self, at: Instant, metrics: &PerformanceMetrics, capacity: usize) {
+        self.timestamps.push(at);
+        self.average_response_time.push(metrics.average_response_time);
+        self.memory_efficiency.push(metrics.memory_efficiency);
+        self.synergy_coefficient.push(metrics.synergy_coefficient);
+        
+        if self.timestamps.len() > capacity {
+            let excess = self.timestamps.len() - capacity;
+            self.timestamps.drain(0..excess);
+            self.average_response_time.drain(0..excess);
+            self.memory_efficiency.drain(0..excess);
+            self.synergy_coefficient.drain(0..excess);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+}
+
+/// Minimum samples before a series is considered to have a trend
+const TREND_MIN_SAMPLES: usize = 3;
+
+/// Per-sample slope below which a series counts as flat
+const TREND_MIN_SLOPE: f64 = 1e-3;
+
+/// Decayed strength below which a connection is dropped
+const DECAY_PRUNE_THRESHOLD: f64 = 0.01;
+
+/// Troubled modules named individually in `narrative_summary`
+const NARRATIVE_MAX_MODULES: usize = 3;
+
+/// Direction a higher-is-better metric is moving in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrendDirection {
+    Improving,
+    Stable,
+    Degrading,
+}
+
+impl TrendDirection {
+    /// Classify `values` by the slope of their least-squares regression line
+    pub fn from_series(values: &[f64]) -> Self {
+        if values.len() < TREND_MIN_SAMPLES {
+            return TrendDirection::Stable;
+        }
+        let n = values.len() as f64;
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = values.iter().sum::<f64>() / n;
+        let (covariance, variance) = values.iter().enumerate()
+            .fold((0.0, 0.0), |(cov, var), (i, y)| {
+                let dx = i as f64 - mean_x;
+                (cov + dx * (y - mean_y), var + dx * dx)
+            });
+        let slope = covariance / variance;
+        if slope > TREND_MIN_SLOPE {
+            TrendDirection::Improving
+        } else if slope < -TREND_MIN_SLOPE {
+            TrendDirection::Degrading
+        } else {
+            TrendDirection::Stable
+        }
+    }
+}
+
+/// Bookkeeping for an undirected edge, keyed by `edge_key`
+#[derive(Debug, Clone)]
+struct EdgeMetadata {
+    last_reinforced: Instant,
+    expires_at: Option<Instant>,
+    /// Remove the edge once neither endpoint has been active for this long
+    idle_ttl: Option<Duration>,
+    /// How many interactions of each operation type reinforced this edge
+    operation_counts: IndexMap<String, u64>,
+}
+
+/// Canonical key for the undirected edge between two modules
+fn edge_key(module_a: &str, module_b: &str) -> (String, String) {
+    if module_a <= module_b {
+        (module_a.to_string(), module_b.to_string())
+    } else {
+        (module_b.to_string(), module_a.to_string())
+    }
+}
+
+/// Remove both directions of an edge and the matching connection entries
+///
+/// Both endpoints are stamped with `version` so incremental exports pick up
+/// the topology change.
+fn remove_edge(
+    registry: &mut IndexMap<String, ModuleMetrics>,
+    synergy_matrix: &mut HashMap<(String, String), f64>,
+    module_a: &str,
+    module_b: &str,
+    version: u64,
+) {
+    synergy_matrix.remove(&(module_a.to_string(), module_b.to_string()));
+    synergy_matrix.remove(&(module_b.to_string(), module_a.to_string()));
+    if let Some(metrics) = registry.get_mut(module_a) {
+        metrics.active_connections.remove(module_b);
+        metrics.version = version;
+    }
+    if let Some(metrics) = registry.get_mut(module_b) {
+        metrics.active_connections.remove(module_a);
+        metrics.version = version;
+    }
+}
+
+/// Flag modules whose strongest connection is to a Critical or Warning peer
+///
+/// A healthy module that leans heavily on an unhealthy one is a cascading
+/// failure risk even though its own metrics look fine.
+fn flag_unhealthy_dependencies(
+    audits: &mut [ModuleAudit],
+    registry: &IndexMap<String, ModuleMetrics>,
+    synergy_matrix: &HashMap<(String, String), f64>,
+) {
+    let statuses: HashMap<String, ModuleStatus> = audits.iter()
+        .map(|audit| (audit.module_name.clone(), audit.status.clone()))
+        .collect();
+    
+    for audit in audits.iter_mut() {
+        let Some(metrics) = registry.get(&audit.module_name) else {
+            continue;
+        };
+        
+        let strongest = metrics.active_connections.iter()
+            .filter(|peer| **peer != audit.module_name)
+            .filter_map(|peer| {
+                synergy_matrix.get(&(audit.module_name.clone(), peer.clone()))
+                    .map(|strength| (peer, *strength))
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        
+        if let Some((peer, strength)) = strongest {
+            if matches!(statuses.get(peer), Some(ModuleStatus::Critical) | Some(ModuleStatus::Warning)) {
+                audit.issues.push(format!(
+                    "Depends on unhealthy module '{}' (strength: {:.2})", peer, strength
+                ));
+                audit.recommendations.push(format!(
+                    "Reduce reliance on '{}' or add a fallback path", peer
+                ));
+            }
+        }
+    }
+}
+
+/// Disjoint groups of modules reachable from each other over `active_connections`
+///
+/// Groups are ordered by their earliest-registered member.
+fn connected_components_in(registry: &IndexMap<String, ModuleMetrics>) -> Vec<HashSet<String>> {
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut components = Vec::new();
+    for start in registry.keys() {
+        if !seen.insert(start.as_str()) {
+            continue;
+        }
+        let mut component = HashSet::new();
+        let mut queue = VecDeque::from([start.as_str()]);
+        while let Some(module) = queue.pop_front() {
+            component.insert(module.to_string());
+            let Some(metrics) = registry.get(module) else {
+                continue;
+            };
+            for peer in &metrics.active_connections {
+                if registry.contains_key(peer) && seen.insert(peer.as_str()) {
+                    queue.push_back(peer.as_str());
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// Mark modules in clusters cut off from the main topology as Critical
+///
+/// Only clusters of two or more modules count: a lone module is already
+/// reported as Disconnected. The largest cluster (earliest registered on a
+/// tie) is treated as the main topology.
+fn flag_isolated_clusters(audits: &mut [ModuleAudit], components: &[HashSet<String>]) {
+    let clusters: Vec<&HashSet<String>> = components.iter()
+        .filter(|component| component.len() > 1)
+        .collect();
+    if clusters.len() < 2 {
+        return;
+    }
+    let main = clusters.iter()
+        .enumerate()
+        .max_by(|(i, a), (j, b)| a.len().cmp(&b.len()).then(j.cmp(i)))
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+    
+    for (index, cluster) in clusters.iter().enumerate() {
+        if index == main {
+            continue;
+        }
+        let mut members: Vec<&str> = cluster.iter().map(|name| name.as_str()).collect();
+        members.sort();
+        for audit in audits.iter_mut().filter(|audit| cluster.contains(&audit.module_name)) {
+            audit.status = ModuleStatus::Critical;
+            audit.issues.push(format!(
+                "Topology split into {} clusters; [{}] is isolated from the main graph",
+                clusters.len(), members.join(", ")
+            ));
+            audit.recommendations.push(
+                "Connect this cluster to the rest of the hypergraph so modules can share memory".to_string()
+            );
+        }
+    }
+}
+
+/// Downgrade modules with a Critical declared dependency to at least Warning
+///
+/// Propagation uses each dependency's own status, so a cascade is reported one
+/// hop at a time rather than turning whole chains Critical.
+fn propagate_dependency_health(audits: &mut [ModuleAudit], dependencies: &HashMap<String, Vec<String>>) {
+    let critical: HashSet<String> = audits.iter()
+        .filter(|audit| audit.status == ModuleStatus::Critical)
+        .map(|audit| audit.module_name.clone())
+        .collect();
+    
+    for audit in audits.iter_mut() {
+        let Some(declared) = dependencies.get(&audit.module_name) else {
+            continue;
+        };
+        
+        for dependency in declared.iter().filter(|d| critical.contains(*d)) {
+            audit.issues.push(format!("Dependency '{}' is Critical", dependency));
+            if audit.status.severity_rank() > ModuleStatus::Warning.severity_rank() {
+                audit.status = ModuleStatus::Warning;
+                audit.recommendations.push(format!(
+                    "Prepare for degraded service until '{}' recovers", dependency
+                ));
+            }
+        }
+    }
+}
+
+/// Serialize `value` as diff-friendly JSON
+///
+/// Object keys are sorted and floats are rounded to a fixed precision, so the
+/// same logical state always produces byte-identical output regardless of
+/// insertion order or last-bit float noise.
+pub fn to_stable_json<T: Serialize>(value: &T) -> Result<String> {
+    let value = stabilize_json(serde_json::to_value(value)?);
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+/// Sort object keys and round floats throughout a JSON value
+pub(crate) fn stabilize_json(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(entries.into_iter().map(|(key, value)| (key, stabilize_json(value))).collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(stabilize_json).collect()),
+        Value::Number(number) if number.is_f64() => {
+            let scale = 10f64.powi(STABLE_JSON_PRECISION);
+            let rounded = number.as_f64()
+                .and_then(|f| serde_json::Number::from_f64((f * scale).round() / scale));
+            Value::Number(rounded.unwrap_or(number))
+        }
+        other => other,
+    }
+}
+
+/// Whether `a` and `b` are equal within `DEFAULT_EPSILON`
+///
+/// Prefer this over `==` when comparing computed scores or strengths, whose
+/// last bits can differ across platforms.
+pub fn approx_eq(a: f64, b: f64) -> bool {
+    approx_eq_within(a, b, DEFAULT_EPSILON)
+}
+
+/// Whether `a` and `b` differ by no more than `epsilon`
+pub fn approx_eq_within(a: f64, b: f64, epsilon: f64) -> bool {
+    (a - b).abs() <= epsilon
+}
+
+/// Whether `value` is below `threshold` by more than `epsilon`
+pub(crate) fn below_threshold(value: f64, threshold: f64, epsilon: f64) -> bool {
+    value < threshold && !approx_eq_within(value, threshold, epsilon)
+}
+
+/// Whether `value` is above `threshold` by more than `epsilon`
+pub(crate) fn above_threshold(value: f64, threshold: f64, epsilon: f64) -> bool {
+    value > threshold && !approx_eq_within(value, threshold, epsilon)
+}
+
+/// Pearson correlation of two equal-length series, `None` if either is constant
+fn pearson_correlation(xs: &[f64], ys: &[f64]) -> Option<f64> {
+    if xs.len() != ys.len() || xs.len() < 2 {
+        return None;
+    }
+    let n = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / n;
+    let mean_y = ys.iter().sum::<f64>() / n;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in xs.iter().zip(ys) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+    Some(covariance / (variance_x * variance_y).sqrt())
+}
+
+/// Shortest a module name is truncated to in width-limited reports
+const MIN_TRUNCATED_NAME: usize = 8;
+
+/// Rescale strengths: MaxScale divides by the strongest edge, ZScore subtracts the mean and divides by the standard deviation
+fn normalized_strengths(synergy_matrix: &HashMap<(String, String), f64>, mode: NormalizationMode) -> HashMap<(String, String), f64> {
+    if synergy_matrix.is_empty() {
+        return HashMap::new();
+    }
+    
+    let count = synergy_matrix.len() as f64;
+    match mode {
+        NormalizationMode::MaxScale => {
+            let max = synergy_matrix.values().cloned().fold(f64::MIN, f64::max);
+            synergy_matrix.iter()
+                .map(|(pair, strength)| {
+                    let value = if max > 0.0 { strength / max } else { 0.0 };
+                    (pair.clone(), value)
+                })
+                .collect()
+        }
+        NormalizationMode::ZScore => {
+            let mean = synergy_matrix.values().sum::<f64>() / count;
+            let variance = synergy_matrix.values()
+                .map(|strength| (strength - mean).powi(2))
+                .sum::<f64>() / count;
+            let stddev = variance.sqrt();
+            synergy_matrix.iter()
+                .map(|(pair, strength)| {
+                    let value = if stddev > 0.0 { (strength - mean) / stddev } else { 0.0 };
+                    (pair.clone(), value)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Whether a report printed to stdout is colorized; `no_color` covers both the
+/// `NO_COLOR` variable and stdout not being a terminal
+pub(crate) fn report_color(force_color: bool, no_color: bool) -> bool {
+    force_color || !no_color
+}
+
+/// Shorten `text` to `max_chars` characters, ending in an ellipsis when cut
+fn truncate_with_ellipsis(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Append `text` as a bulleted report line, wrapping at `max_width` (0 = no wrapping)
+///
+/// Continuation lines are indented to align with the text after the bullet.
+fn push_wrapped(report: &mut String, bullet: &str, text: &str, max_width: usize) {
+    if max_width == 0 {
+        report.push_str(&format!("{}{}\n", bullet, text));
+        return;
+    }
+    let indent = " ".repeat(bullet.chars().count());
+    let options = textwrap::Options::new(max_width)
+        .initial_indent(bullet)
+        .subsequent_indent(&indent);
+    for line in textwrap::wrap(text, options) {
+        report.push_str(&line);
+        report.push('\n');
+    }
+}
+
+/// Operations and errors aggregated over one `METRICS_BUCKET_WIDTH` slice of time
+#[derive(Debug, Clone)]
+struct MetricsBucket {
+    start: Instant,
+    operations: u64,
+    errors: u64,
+    total_latency: Duration,
+    module_errors: HashMap<String, u64>,
+}
+
+impl HypergraphCoordinator {
+    /// Create a new hypergraph coordinator instance
+    pub fn new(config: GlobalConfig) -> Self {
+        Self {
+            config,
+            settings: HypergraphConfig::default(),
+            clock: Arc::new(SystemClock),
+            module_registry: RwLock::new(IndexMap::new()),
+            synergy_matrix: RwLock::new(HashMap::new()),
+            edge_metadata: RwLock::new(HashMap::new()),
+            audit_history: RwLock::new(Vec::new()),
+            performance_metrics: RwLock::new(PerformanceMetrics::default()),
+            metrics_window: RwLock::new(VecDeque::new()),
+            operation_times: RwLock::new(HashMap::new()),
+            performance_series: RwLock::new(PerformanceSeries::default()),
+            staleness_windows: RwLock::new(HashMap::new()),
+            memory_samplers: RwLock::new(HashMap::new()),
+            last_statuses: RwLock::new(HashMap::new()),
+            topology_frozen: AtomicBool::new(false),
+            change_counter: AtomicU64::new(0),
+            retrievals: RwLock::new(VecDeque::new()),
+            audit_cache: RwLock::new(None),
+            module_weights: RwLock::new(HashMap::new()),
+            snapshot_store: None,
+            last_save: RwLock::new(None),
+            audit_errors: RwLock::new(Vec::new()),
+            dependencies: RwLock::new(HashMap::new()),
+            latency_histograms: RwLock::new(HashMap::new()),
+            expected_topology: RwLock::new(None),
+            matrix_evolution: RwLock::new(VecDeque::new()),
+            recorder: parking_lot::Mutex::new(None),
+            auto_healing_overrides: RwLock::new(HashMap::new()),
+            events: RwLock::new(VecDeque::new()),
+            op_aliases: RwLock::new(HashMap::new()),
+            status_events: tokio::sync::broadcast::channel(STATUS_EVENT_CAPACITY).0,
+            circuit_breakers: RwLock::new(HashMap::new()),
+            operation_latencies: RwLock::new(HashMap::new()),
+            in_flight: RwLock::new(HashMap::new()),
+            next_operation_id: AtomicU64::new(0),
+            merged_samples: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Use the given hypergraph settings instead of the defaults
+    ///
+    /// Fails if the settings do not pass `HypergraphConfig::validate`.
+    pub fn with_settings(mut self, settings: HypergraphConfig) -> Result<Self> {
+        settings.validate()?;
+        self.settings = settings;
+        Ok(self)
+    }
+
+    /// Replace the time source, mainly so tests can control elapsed time
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Persist snapshots to `store` every `autosave_interval_seconds`
+    pub fn with_snapshot_store(mut self, store: Arc<dyn SnapshotStore>) -> Self {
+        self.snapshot_store = Some(store);
+        self
+    }
+
+    /// Report which optional behaviors are enabled by the coordinator's settings
+    pub fn enabled_features(&self) -> FeatureFlags {
+        FeatureFlags {
+            decay: self.settings.connection_strength_decay < 1.0,
+            auto_healing: self.settings.enable_auto_healing,
+            edge_eviction: self.settings.max_edges > 0,
+            persistence: self.snapshot_store.is_some() && self.settings.autosave_interval_seconds > 0,
+            circuit_breakers: self.settings.max_module_errors > 0,
+        }
+    }
+
+    /// Next value of the change counter used to version module mutations
+    fn next_version(&self) -> u64 {
+        self.change_counter.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Current time according to the coordinator's clock
+    fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Apply `update` to the metrics bucket covering the current time
+    fn update_metrics_bucket(&self, update: impl FnOnce(&mut MetricsBucket)) {
+        let now = self.now();
+        let mut window = self.metrics_window.write();
+
+        let needs_bucket = window
+            .back()
+            .map(|bucket| now.duration_since(bucket.start) >= METRICS_BUCKET_WIDTH)
+            .unwrap_or(true);
+        if needs_bucket {
+            window.push_back(MetricsBucket {
+                start: now,
+                operations: 0,
+                errors: 0,
+                total_latency: Duration::ZERO,
+                module_errors: HashMap::new(),
+            });
+        }
+        if let Some(bucket) = window.back_mut() {
+            update(bucket);
+        }
+
+        while let Some(front) = window.front() {
+            if now.duration_since(front.start) > METRICS_RETENTION {
+                window.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Lock the topology: new modules and connections are rejected while
+    /// activity and errors continue to be recorded
+    pub fn freeze_topology(&self) {
+        self.topology_frozen.store(true, Ordering::SeqCst);
+        log::info!("Hypergraph topology frozen");
+    }
+
+    /// Allow topology changes again after `freeze_topology`
+    pub fn unfreeze_topology(&self) {
+        self.topology_frozen.store(false, Ordering::SeqCst);
+        log::info!("Hypergraph topology unfrozen");
+    }
+
+    pub fn is_topology_frozen(&self) -> bool {
+        self.topology_frozen.load(Ordering::SeqCst)
+    }
+
+    fn ensure_topology_mutable(&self) -> Result<()> {
+        if self.is_topology_frozen() {
+            return Err(anyhow!("Hypergraph topology is frozen"));
+        }
+        Ok(())
+    }
+
+    /// Register a core module for hypergraph tracking
+    pub fn register_module(&self, module_name: &str) -> Result<()> {
+        self.ensure_topology_mutable()?;
+        let mut registry = self.module_registry.write();
+        registry.insert(module_name.to_string(), self.new_module_metrics(module_name));
+        drop(registry);
+        self.record_event(|| RecordedEventKind::RegisterModule { module: module_name.to_string() });
+        
+        log::info!("Registered module '{}' for hypergraph coordination", module_name);
+        Ok(())
+    }
+
+    /// Register a batch of modules under a single registry lock
+    ///
+    /// Unlike `register_module`, names that are already registered (or repeated
+    /// within the batch) are an error, and then nothing is registered.
+    pub fn register_modules(&self, names: &[&str]) -> Result<()> {
+        self.ensure_topology_mutable()?;
+        let mut registry = self.module_registry.write();
+        
+        let mut seen = HashSet::new();
+        let conflicts: Vec<&str> = names.iter()
+            .copied()
+            .filter(|name| registry.contains_key(*name) || !seen.insert(*name))
+            .collect();
+        if !conflicts.is_empty() {
+            return Err(anyhow!("Modules already registered: {}", conflicts.join(", ")));
+        }
+        
+        for name in names {
+            registry.insert(name.to_string(), self.new_module_metrics(name));
+        }
+        drop(registry);
+        for name in names {
+            self.record_event(|| RecordedEventKind::RegisterModule { module: name.to_string() });
+        }
+        
+        log::info!("Registered modules {:?} for hypergraph coordination", names);
+        Ok(())
+    }
+
+    fn new_module_metrics(&self, module_name: &str) -> ModuleMetrics {
+        ModuleMetrics {
+            name: module_name.to_string(),
+            active_connections: HashSet::new(),
+            message_count: 0,
+            error_count: 0,
+            last_activity: self.now(),
+            memory_usage: 0,
+            cognitive_load: 0.0,
+            declared_operations: None,
+            undeclared_operations: HashSet::new(),
+            lifecycle: ModuleLifecycle::Running,
+            gauges: IndexMap::new(),
+            recent_errors: VecDeque::new(),
+            version: self.next_version(),
+        }
+    }
+
+    /// Remove a module and every connection to or from it
+    ///
+    /// Per-module settings, samplers and breaker state are dropped too, so
+    /// re-registering the name starts from scratch. Audit history is kept.
+    pub fn unregister_module(&self, module_name: &str) -> Result<()> {
+        self.ensure_topology_mutable()?;
+        let mut registry = self.module_registry.write();
+        if registry.shift_remove(module_name).is_none() {
+            return Err(anyhow!("Module '{}' is not registered", module_name));
+        }
+        for metrics in registry.values_mut() {
+            if metrics.active_connections.remove(module_name) {
+                metrics.version = self.next_version();
+            }
+        }
+        self.synergy_matrix.write().retain(|(a, b), _| a != module_name && b != module_name);
+        self.edge_metadata.write().retain(|(a, b), _| a != module_name && b != module_name);
+        self.merged_samples.write().retain(|(a, b), _| a != module_name && b != module_name);
+        drop(registry);
+        
+        self.staleness_windows.write().remove(module_name);
+        self.memory_samplers.write().remove(module_name);
+        self.last_statuses.write().remove(module_name);
+        self.module_weights.write().remove(module_name);
+        self.latency_histograms.write().remove(module_name);
+        self.auto_healing_overrides.write().remove(module_name);
+        self.circuit_breakers.write().remove(module_name);
+        self.in_flight.write().remove(module_name);
+        let mut dependencies = self.dependencies.write();
+        dependencies.remove(module_name);
+        for deps in dependencies.values_mut() {
+            deps.retain(|dep| dep != module_name);
+        }
+        drop(dependencies);
+        *self.audit_cache.write() = None;
+        
+        self.record_event(|| RecordedEventKind::UnregisterModule { module: module_name.to_string() });
+        log::info!("Unregistered module '{}' from hypergraph coordination", module_name);
+        Ok(())
+    }
+
+    /// Register a module along with the operation types it is expected to record
+    pub fn register_module_with_ops(&self, module_name: &str, operations: &[&str]) -> Result<()> {
+        self.register_module(module_name)?;
+        
+        if let Some(metrics) = self.module_registry.write().get_mut(module_name) {
+            metrics.declared_operations = Some(operations.iter().map(|op| op.to_string()).collect());
+        }
+        Ok(())
+    }
+
+    /// Establish a synergy connection between two modules
+    ///
+    /// Sets the same strength in both directions, exactly like one
+    /// `establish_directed_connection` per direction. Strengths outside
+    /// `[0, 1]`, including NaN, are rejected.
+    pub fn establish_connection(&self, module_a: &str, module_b: &str, strength: f64) -> Result<()> {
+        self.connect(module_a, module_b, strength, false)
+    }
+
+    /// Set the strength of the connection from `from` to `to` only
+    ///
+    /// Both modules list each other in `active_connections`, so connectivity,
+    /// components and TTLs treat the edge as undirected. Strengths, however,
+    /// are per direction: a module's synergy score averages its *outgoing*
+    /// strengths, so this only moves `from`'s score. The `to -> from`
+    /// direction keeps its own strength, or has none until set.
+    pub fn establish_directed_connection(&self, from: &str, to: &str, strength: f64) -> Result<()> {
+        self.connect(from, to, strength, true)
+    }
+
+    fn connect(&self, module_a: &str, module_b: &str, strength: f64, directed: bool) -> Result<()> {
+        // Synergy scoring assumes strengths in [0, 1]; NaN fails this check too
+        if !(0.0..=1.0).contains(&strength) {
+            return Err(anyhow!("Connection strength must be within [0, 1], got {}", strength));
+        }
+        self.ensure_topology_mutable()?;
+        let mut registry = self.module_registry.write();
+        let mut synergy_matrix = self.synergy_matrix.write();
+        let mut edge_metadata = self.edge_metadata.write();
+        
+        self.set_directed_strength(&mut registry, &mut synergy_matrix, &mut edge_metadata, module_a, module_b, strength);
+        if !directed {
+            self.set_directed_strength(&mut registry, &mut synergy_matrix, &mut edge_metadata, module_b, module_a, strength);
+        }
+        
+        self.record_event(|| RecordedEventKind::Connect {
+            module_a: module_a.to_string(),
+            module_b: module_b.to_string(),
+            strength,
+            directed,
+        });
+        log::debug!("Established connection: {} {} {} (strength: {:.2})", 
+                   module_a, if directed { "->" } else { "<->" }, module_b, strength);
+        
+        if self.settings.max_edges > 0 {
+            self.evict_excess_edges(&mut registry, &mut synergy_matrix, &mut edge_metadata, module_a, module_b);
+        }
+        Ok(())
+    }
+
+    fn set_directed_strength(
+        &self,
+        registry: &mut IndexMap<String, ModuleMetrics>,
+        synergy_matrix: &mut HashMap<(String, String), f64>,
+        edge_metadata: &mut HashMap<(String, String), EdgeMetadata>,
+        from: &str,
+        to: &str,
+        strength: f64,
+    ) {
+        // Update module connections
+        if let Some(metrics_from) = registry.get_mut(from) {
+            metrics_from.active_connections.insert(to.to_string());
+            metrics_from.version = self.next_version();
+        }
+        if let Some(metrics_to) = registry.get_mut(to) {
+            metrics_to.active_connections.insert(from.to_string());
+            metrics_to.version = self.next_version();
+        }
+        
+        // Update synergy matrix; a local write replaces any merged average
+        synergy_matrix.insert((from.to_string(), to.to_string()), strength);
+        self.merged_samples.write().remove(&(from.to_string(), to.to_string()));
+        
+        let key = edge_key(from, to);
+        let operation_counts = edge_metadata.remove(&key)
+            .map(|meta| meta.operation_counts)
+            .unwrap_or_default();
+        edge_metadata.insert(key, EdgeMetadata {
+            last_reinforced: self.now(),
+            expires_at: None,
+            idle_ttl: None,
+            operation_counts,
+        });
+    }
+
+    /// Establish a transient connection that is removed once `ttl` elapses
+    ///
+    /// Expired edges are pruned by `expire_edges`, which runs at the start of
+    /// every audit. Re-establishing the edge without a TTL makes it permanent.
+    pub fn establish_connection_ttl(&self, module_a: &str, module_b: &str, strength: f64, ttl: Duration) -> Result<()> {
+        self.establish_connection(module_a, module_b, strength)?;
+        self.set_edge_ttl(module_a, module_b, ttl);
+        Ok(())
+    }
+
+    fn set_edge_ttl(&self, module_a: &str, module_b: &str, ttl: Duration) {
+        let expires_at = self.now() + ttl;
+        if let Some(meta) = self.edge_metadata.write().get_mut(&edge_key(module_a, module_b)) {
+            meta.expires_at = Some(expires_at);
+        }
+        self.record_event(|| RecordedEventKind::ConnectionTtl {
+            module_a: module_a.to_string(),
+            module_b: module_b.to_string(),
+            ttl_ms: ttl.as_millis() as u64,
+        });
+    }
+
+    /// Establish a connection that is removed once both endpoints sit idle for `idle_ttl`
+    ///
+    /// Unlike `establish_connection_ttl`, activity on either endpoint keeps the
+    /// edge alive however much wall-clock time passes. Expiry is evaluated
+    /// against each module's `last_activity` by `expire_edges`.
+    pub fn establish_connection_idle_ttl(
+        &self,
+        module_a: &str,
+        module_b: &str,
+        strength: f64,
+        idle_ttl: Duration,
+    ) -> Result<()> {
+        self.establish_connection(module_a, module_b, strength)?;
+        self.set_edge_idle_ttl(module_a, module_b, idle_ttl);
+        Ok(())
+    }
+
+    fn set_edge_idle_ttl(&self, module_a: &str, module_b: &str, idle_ttl: Duration) {
+        if let Some(meta) = self.edge_metadata.write().get_mut(&edge_key(module_a, module_b)) {
+            meta.idle_ttl = Some(idle_ttl);
+        }
+        self.record_event(|| RecordedEventKind::ConnectionIdleTtl {
+            module_a: module_a.to_string(),
+            module_b: module_b.to_string(),
+            idle_ttl_ms: idle_ttl.as_millis() as u64,
+        });
+    }
+
+    /// Reinforce the edge between two modules on behalf of `operation_type`
+    ///
+    /// The edge is created at `strength` if missing, otherwise it keeps the
+    /// stronger of its current and the given strength. Each call is tallied
+    /// per operation type so `edge_operation_counts` can explain what drives
+    /// the connection.
+    pub fn record_interaction(&self, module_a: &str, module_b: &str, operation_type: &str, strength: f64) -> Result<()> {
+        let current = self.synergy_matrix.read()
+            .get(&(module_a.to_string(), module_b.to_string()))
+            .copied()
+            .unwrap_or(0.0);
+        self.establish_connection(module_a, module_b, current.max(strength))?;
+        
+        let operation_type = self.canonical_operation(operation_type);
+        if let Some(meta) = self.edge_metadata.write().get_mut(&edge_key(module_a, module_b)) {
+            *meta.operation_counts.entry(operation_type).or_insert(0) += 1;
+        }
+        Ok(())
+    }
+
+    /// Per-operation-type interaction tallies for the edge between two modules
+    ///
+    /// Returns `None` when the modules are not connected.
+    pub fn edge_operation_counts(&self, module_a: &str, module_b: &str) -> Option<IndexMap<String, u64>> {
+        self.edge_metadata.read()
+            .get(&edge_key(module_a, module_b))
+            .map(|meta| meta.operation_counts.clone())
+    }
+
+    /// Remove every connection whose TTL or idle TTL has elapsed, returning how many were removed
+    ///
+    /// Nothing expires while the topology is frozen; lapsed edges are removed
+    /// by the first call after it is unfrozen.
+    pub fn expire_edges(&self) -> usize {
+        if self.is_topology_frozen() {
+            return 0;
+        }
+        let now = self.now();
+        let mut registry = self.module_registry.write();
+        let mut synergy_matrix = self.synergy_matrix.write();
+        let mut edge_metadata = self.edge_metadata.write();
+        
+        let idle_for = |module: &str| registry.get(module)
+            .map(|metrics| now.duration_since(metrics.last_activity));
+        let expired: Vec<(String, String)> = edge_metadata.iter()
+            .filter(|((a, b), meta)| {
+                let ttl_elapsed = meta.expires_at.is_some_and(|at| now >= at);
+                // A freshly (re)established edge gets a full idle period of its own
+                let abandoned = meta.idle_ttl.is_some_and(|idle_ttl| {
+                    now.duration_since(meta.last_reinforced) >= idle_ttl
+                        && [a, b].iter().all(|module| idle_for(module).is_none_or(|idle| idle >= idle_ttl))
+                });
+                ttl_elapsed || abandoned
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        
+        for (a, b) in &expired {
+            remove_edge(&mut registry, &mut synergy_matrix, a, b, self.next_version());
+            edge_metadata.remove(&(a.clone(), b.clone()));
+            log::debug!("Expired transient connection: {} <-> {}", a, b);
+        }
+        
+        expired.len()
+    }
+
+    /// Audit on a background task every `interval` until the handle is aborted
+    ///
+    /// Each cycle decays connections, then audits and applies auto-healing
+    /// wherever it is enabled globally or by a per-module override.
+    pub fn spawn_audit_loop(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let reinforcement = ConfigReinforcement::new(self.settings.clone());
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let decayed = self.decay_connections();
+                match reinforcement.apply_auto_healing_to(&self).await {
+                    Ok(outcomes) => log::debug!(
+                        "Audit loop cycle: {} connections decayed away, {} healing actions",
+                        decayed, outcomes.len()
+                    ),
+                    Err(err) => log::warn!("Audit loop cycle failed: {}", err),
+                }
+            }
+        })
+    }
+
+    /// Multiply every connection strength by `connection_strength_decay`
+    ///
+    /// Both directions of an edge decay by the same factor, but each is
+    /// pruned on its own once it falls below `DECAY_PRUNE_THRESHOLD`. The
+    /// modules' `active_connections` entries are dropped only when neither
+    /// direction remains. Returns how many edges were removed. Decay is
+    /// skipped entirely while the topology is frozen.
+    pub fn decay_connections(&self) -> usize {
+        let factor = self.settings.connection_strength_decay;
+        if factor >= 1.0 || self.is_topology_frozen() {
+            return 0;
+        }
+        let mut registry = self.module_registry.write();
+        let mut synergy_matrix = self.synergy_matrix.write();
+        let mut edge_metadata = self.edge_metadata.write();
+        
+        for strength in synergy_matrix.values_mut() {
+            *strength *= factor;
+        }
+        let weak: Vec<(String, String)> = synergy_matrix.iter()
+            .filter(|(_, strength)| **strength < DECAY_PRUNE_THRESHOLD)
+            .map(|(pair, _)| pair.clone())
+            .collect();
+        for pair in &weak {
+            synergy_matrix.remove(pair);
+        }
+        let mut pruned: Vec<(String, String)> = weak.iter()
+            .map(|(a, b)| edge_key(a, b))
+            .filter(|(a, b)| {
+                !synergy_matrix.contains_key(&(a.clone(), b.clone()))
+                    && !synergy_matrix.contains_key(&(b.clone(), a.clone()))
+            })
+            .collect();
+        pruned.sort();
+        pruned.dedup();
+        
+        for (a, b) in &pruned {
+            remove_edge(&mut registry, &mut synergy_matrix, a, b, self.next_version());
+            edge_metadata.remove(&(a.clone(), b.clone()));
+            log::debug!("Decayed connection dropped: {} <-> {}", a, b);
+        }
+        
+        pruned.len()
+    }
+
+    /// Evict the weakest edges until the matrix is within `max_edges`
+    ///
+    /// Candidates are ordered by strength and then by how long ago they were
+    /// last reinforced; the edge between `keep_a` and `keep_b` is never evicted.
+    fn evict_excess_edges(
+        &self,
+        registry: &mut IndexMap<String, ModuleMetrics>,
+        synergy_matrix: &mut HashMap<(String, String), f64>,
+        edge_metadata: &mut HashMap<(String, String), EdgeMetadata>,
+        keep_a: &str,
+        keep_b: &str,
+    ) {
+        let keep = edge_key(keep_a, keep_b);
+        // An edge is as strong as its stronger direction
+        let mut strengths: HashMap<(String, String), f64> = HashMap::new();
+        for ((a, b), strength) in synergy_matrix.iter() {
+            let entry = strengths.entry(edge_key(a, b)).or_insert(*strength);
+            *entry = entry.max(*strength);
+        }
+        let mut edges: Vec<((String, String), f64, Option<Instant>)> = strengths.into_iter()
+            .map(|(key, strength)| {
+                let last_reinforced = edge_metadata.get(&key).map(|meta| meta.last_reinforced);
+                (key, strength, last_reinforced)
+            })
+            .collect();
+        
+        if edges.len() <= self.settings.max_edges {
+            return;
+        }
+        
+        edges.retain(|(key, _, _)| *key != keep);
+        edges.sort_by(|a, b| {
+            a.1.partial_cmp(&b.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.2.cmp(&b.2))
+        });
+        
+        let excess = edges.len() + 1 - self.settings.max_edges;
+        for ((a, b), strength, _) in edges.into_iter().take(excess) {
+            remove_edge(registry, synergy_matrix, &a, &b, self.next_version());
+            edge_metadata.remove(&(a.clone(), b.clone()));
+            log::debug!("Evicted connection: {} <-> {} (strength: {:.2})", a, b, strength);
+        }
+    }
+
+    /// Record module activity for hypergraph analysis
+    pub fn record_activity(&self, module_name: &str, operation_type: &str, duration: Duration) -> Result<()> {
+        self.record_activity_inner(module_name, operation_type, duration, None)
+    }
+
+    /// Record module activity tagged with the trace it belongs to
+    ///
+    /// The trace ID is kept as an exemplar on the module's latency histogram so
+    /// a latency spike can be followed back to a concrete trace.
+    pub fn record_activity_traced(
+        &self,
+        module_name: &str,
+        operation_type: &str,
+        duration: Duration,
+        trace_id: &str,
+    ) -> Result<()> {
+        self.record_activity_inner(module_name, operation_type, duration, Some(trace_id))
+    }
+
+    /// Record module activity along with the change in memory it caused
+    ///
+    /// A negative `memory_delta` releases memory; usage never drops below zero.
+    pub fn record_activity_with_memory(
+        &self,
+        module_name: &str,
+        operation_type: &str,
+        duration: Duration,
+        memory_delta: i64,
+    ) -> Result<()> {
+        self.record_activity_inner(module_name, operation_type, duration, None)?;
+        
+        if let Some(metrics) = self.module_registry.write().get_mut(module_name) {
+            let delta = usize::try_from(memory_delta.unsigned_abs()).unwrap_or(usize::MAX);
+            metrics.memory_usage = if memory_delta < 0 {
+                metrics.memory_usage.saturating_sub(delta)
+            } else {
+                metrics.memory_usage.saturating_add(delta)
+            };
+        }
+        Ok(())
+    }
+
+    fn record_activity_inner(
+        &self,
+        module_name: &str,
+        operation_type: &str,
+        duration: Duration,
+        trace_id: Option<&str>,
+    ) -> Result<()> {
+        let canonical = self.canonical_operation(operation_type);
+        let operation_type = canonical.as_str();
+        if !self.module_registry.read().contains_key(module_name) {
+            match self.settings.unknown_module_policy {
+                UnknownModulePolicy::Ignore => {}
+                UnknownModulePolicy::Strict => {
+                    return Err(anyhow!(
+                        "Module '{}' recorded activity but is not registered",
+                        module_name
+                    ));
+                }
+                UnknownModulePolicy::AutoRegister => {
+                    log::info!("Auto-registering module '{}' on first activity", module_name);
+                    self.register_module(module_name)?;
+                }
+            }
+        }
+        let mut registry = self.module_registry.write();
+        let registered = registry.contains_key(module_name);
+        
+        if let Some(metrics) = registry.get_mut(module_name) {
+            let undeclared = metrics.declared_operations.as_ref()
+                .is_some_and(|declared| !declared.contains(operation_type));
+            if undeclared {
+                match self.settings.undeclared_operation_policy {
+                    UndeclaredOperationPolicy::Error => {
+                        return Err(anyhow!(
+                            "Module '{}' recorded undeclared operation type '{}'",
+                            module_name, operation_type
+                        ));
+                    }
+                    UndeclaredOperationPolicy::Warn => {
+                        log::warn!("Module '{}' recorded undeclared operation type '{}'", 
+                                  module_name, operation_type);
+                        metrics.undeclared_operations.insert(operation_type.to_string());
+                    }
+                }
+            }
+            
+            metrics.message_count += 1;
+            metrics.version = self.next_version();
+            metrics.last_activity = self.now();
+            
+            if let Some(breaker) = self.circuit_breakers.write().get_mut(module_name) {
+                if breaker.state != CircuitState::Closed {
+                    log::info!("Circuit breaker closed for module '{}'", module_name);
+                }
+                *breaker = CircuitBreaker::default();
+            }
+            
+            // Update cognitive load based on operation type and duration
+            let load_factor = self.settings.load_factors.get(operation_type)
+                .copied()
+                .unwrap_or(DEFAULT_LOAD_FACTOR);
+            
+            let alpha = self.settings.cognitive_load_alpha;
+            metrics.cognitive_load = (metrics.cognitive_load * (1.0 - alpha)) + 
+                                   (load_factor * duration.as_secs_f64() * alpha);
+        }
+        
+        // Update global performance metrics
+        let mut perf = self.performance_metrics.write();
+        perf.total_operations += 1;
+        
+        // Exponential moving average for response time
+        let alpha = self.settings.response_time_alpha;
+        let current_avg = perf.average_response_time.as_secs_f64();
+        let new_avg = current_avg * (1.0 - alpha) + duration.as_secs_f64() * alpha;
+        perf.average_response_time = Duration::from_secs_f64(new_avg);
+        drop(perf);
+        drop(registry);
+        
+        *self.operation_times.write()
+            .entry(operation_type.to_string())
+            .or_insert(Duration::ZERO) += duration;
+        self.operation_latencies.write()
+            .entry(operation_type.to_string())
+            .or_default()
+            .observe(duration);
+        
+        self.update_metrics_bucket(|bucket| {
+            bucket.operations += 1;
+            bucket.total_latency += duration;
+        });
+        
+        if registered {
+            self.latency_histograms.write()
+                .entry(module_name.to_string())
+                .or_default()
+                .observe(duration, trace_id);
+        }
+        self.record_event(|| RecordedEventKind::Activity {
+            module: module_name.to_string(),
+            operation: operation_type.to_string(),
+            duration_us: duration.as_micros() as u64,
+        });
+        
+        Ok(())
+    }
+
+    /// Start tracking an operation on `module_name` until the returned guard is dropped
+    ///
+    /// Operations still running after `stuck_operation_seconds` make the audit
+    /// flag the module as potentially stuck, which catches hangs that never
+    /// reach `record_activity`. Unregistered modules are rejected unless
+    /// `unknown_module_policy` is `AutoRegister`.
+    pub fn begin_operation(&self, module_name: &str, operation_type: &str) -> Result<OperationGuard<'_>> {
+        if !self.module_registry.read().contains_key(module_name) {
+            if !matches!(self.settings.unknown_module_policy, UnknownModulePolicy::AutoRegister) {
+                return Err(anyhow!("Module '{}' is not registered", module_name));
+            }
+            self.register_module(module_name)?;
+        }
+        let id = self.next_operation_id.fetch_add(1, Ordering::Relaxed);
+        let started = self.now();
+        self.in_flight.write()
+            .entry(module_name.to_string())
+            .or_default()
+            .insert(id, started);
+        Ok(OperationGuard {
+            coordinator: self,
+            module: module_name.to_string(),
+            operation_type: operation_type.to_string(),
+            id,
+            started,
+        })
+    }
+
+    /// Number of operations `module_name` has started but not yet finished
+    pub fn in_flight_operations(&self, module_name: &str) -> usize {
+        self.in_flight.read().get(module_name).map_or(0, HashMap::len)
+    }
+
+    /// Per-module latency histograms, in registration order
+    pub(crate) fn latency_histograms(&self) -> Vec<(String, LatencyHistogram)> {
+        let histograms = self.latency_histograms.read();
+        self.module_registry.read()
+            .keys()
+            .filter_map(|name| histograms.get(name).map(|h| (name.clone(), h.clone())))
+            .collect()
+    }
+
+    /// Record an error for error tracking and module health assessment
+    pub fn record_error(&self, module_name: &str, error: &str) -> Result<()> {
+        let mut registry = self.module_registry.write();
+        
+        if let Some(metrics) = registry.get_mut(module_name) {
+            metrics.error_count += 1;
+            metrics.version = self.next_version();
+            if metrics.recent_errors.len() == RECENT_ERRORS_CAPACITY {
+                metrics.recent_errors.pop_front();
+            }
+            metrics.recent_errors.push_back((self.now(), error.to_string()));
+            log::warn!("Module '{}' error: {}", module_name, error);
+            drop(registry);
+            self.trip_circuit_on_error(module_name);
+            
+            self.update_metrics_bucket(|bucket| {
+                bucket.errors += 1;
+                *bucket.module_errors.entry(module_name.to_string()).or_insert(0) += 1;
+            });
+            self.record_event(|| RecordedEventKind::Error {
+                module: module_name.to_string(),
+                message: error.to_string(),
+            });
+        }
+        
+        Ok(())
+    }
+
+    /// Up to the last `RECENT_ERRORS_CAPACITY` errors recorded for `module_name`, oldest first
+    pub fn recent_errors(&self, module_name: &str) -> Vec<(Instant, String)> {
+        self.module_registry.read()
+            .get(module_name)
+            .map(|metrics| metrics.recent_errors.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Count a consecutive error and open the breaker once `max_module_errors` is reached
+    ///
+    /// A failed half-open trial reopens the breaker immediately.
+    fn trip_circuit_on_error(&self, module_name: &str) {
+        let threshold = self.settings.max_module_errors;
+        if threshold == 0 {
+            return;
+        }
+        let mut breakers = self.circuit_breakers.write();
+        let breaker = breakers.entry(module_name.to_string()).or_default();
+        breaker.consecutive_errors += 1;
+        let trip = match breaker.state {
+            CircuitState::Closed => breaker.consecutive_errors >= threshold,
+            CircuitState::HalfOpen => true,
+            CircuitState::Open { .. } => false,
+        };
+        if trip {
+            breaker.state = CircuitState::Open { since: self.now() };
+            log::warn!("Circuit breaker opened for module '{}' after {} consecutive errors",
+                       module_name, breaker.consecutive_errors);
+        }
+    }
+
+    /// Current circuit breaker state of `module_name`, without advancing it
+    pub fn circuit_state(&self, module_name: &str) -> CircuitState {
+        self.circuit_breakers.read()
+            .get(module_name)
+            .map_or(CircuitState::Closed, |breaker| breaker.state)
+    }
+
+    /// Whether callers should send work to `module_name`
+    ///
+    /// False while the breaker is open. Once `circuit_half_open_seconds` have
+    /// passed, the first caller is let through as a trial and the breaker goes
+    /// half-open; further callers are refused until that trial's outcome is
+    /// recorded. Successful activity closes the breaker, an error reopens it.
+    pub fn is_available(&self, module_name: &str) -> bool {
+        let mut breakers = self.circuit_breakers.write();
+        let Some(breaker) = breakers.get_mut(module_name) else {
+            return true;
+        };
+        match breaker.state {
+            CircuitState::Closed => true,
+            CircuitState::HalfOpen => false,
+            CircuitState::Open { since } => {
+                let cooldown = Duration::from_secs(self.settings.circuit_half_open_seconds);
+                if self.now().duration_since(since) >= cooldown {
+                    breaker.state = CircuitState::HalfOpen;
+                    log::info!("Circuit breaker half-open for module '{}'; allowing a trial call", module_name);
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Start appending every registration, connection, activity and error to `path`
+    ///
+    /// Events are written as JSON lines and can be fed to `replay_until`.
+    /// Connection TTLs and unregistrations are recorded too. Other mutations,
+    /// such as decay, merges, healing, error clearing and counter resets, as
+    /// well as per-module settings, are not recorded and so not replayed.
+    pub fn start_recording(&self, path: &std::path::Path) -> Result<()> {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|err| anyhow!("Failed to open recording '{}': {}", path.display(), err))?;
+        let started_wall_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+        *self.recorder.lock() = Some(ActivityRecorder {
+            writer: std::io::LineWriter::new(file),
+            started_at: self.now(),
+            started_wall_ms,
+        });
+        Ok(())
+    }
+
+    /// Stop recording; events after this call are not written
+    pub fn stop_recording(&self) {
+        self.recorder.lock().take();
+    }
+
+    fn record_event(&self, kind: impl FnOnce() -> RecordedEventKind) {
+        let mut recorder = self.recorder.lock();
+        let Some(recorder) = recorder.as_mut() else {
+            return;
+        };
+        let at_ms = recorder.started_wall_ms + self.now().duration_since(recorder.started_at).as_millis() as u64;
+        let event = RecordedEvent { at_ms, kind: kind() };
+        let written = serde_json::to_string(&event)
+            .map_err(anyhow::Error::from)
+            .and_then(|line| {
+                use std::io::Write;
+                writeln!(recorder.writer, "{}", line).map_err(anyhow::Error::from)
+            });
+        if let Err(err) = written {
+            log::warn!("Failed to record hypergraph event: {}", err);
+        }
+    }
+
+    /// Rebuild a coordinator from a recording, applying only events up to `until_ms`
+    ///
+    /// `until_ms` is a Unix timestamp in milliseconds. The replayed coordinator
+    /// runs on a `ManualClock` advanced between events, so idle times and
+    /// rolling windows match the original timeline.
+    pub fn replay_until(config: GlobalConfig, path: &std::path::Path, until_ms: u64) -> Result<Self> {
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = Self::new(config).with_clock(clock.clone());
+        
+        let mut previous_ms = None;
+        for event in read_recording(path)?.into_iter().take_while(|event| event.at_ms <= until_ms) {
+            if let Some(previous_ms) = previous_ms {
+                clock.advance(Duration::from_millis(event.at_ms.saturating_sub(previous_ms)));
+            }
+            previous_ms = Some(event.at_ms);
+            
+            match event.kind {
+                RecordedEventKind::RegisterModule { module } => coordinator.register_module(&module)?,
+                RecordedEventKind::Connect { module_a, module_b, strength, directed: true } => {
+                    coordinator.establish_directed_connection(&module_a, &module_b, strength)?
+                }
+                RecordedEventKind::Connect { module_a, module_b, strength, directed: false } => {
+                    coordinator.establish_connection(&module_a, &module_b, strength)?
+                }
+                RecordedEventKind::Activity { module, operation, duration_us } => {
+                    coordinator.record_activity(&module, &operation, Duration::from_micros(duration_us))?
+                }
+                RecordedEventKind::Error { module, message } => coordinator.record_error(&module, &message)?,
+                RecordedEventKind::UnregisterModule { module } => coordinator.unregister_module(&module)?,
+                RecordedEventKind::ConnectionTtl { module_a, module_b, ttl_ms } => {
+                    coordinator.set_edge_ttl(&module_a, &module_b, Duration::from_millis(ttl_ms))
+                }
+                RecordedEventKind::ConnectionIdleTtl { module_a, module_b, idle_ttl_ms } => {
+                    coordinator.set_edge_idle_ttl(&module_a, &module_b, Duration::from_millis(idle_ttl_ms))
+                }
+            }
+        }
+        
+        Ok(coordinator)
+    }
+
+    /// Fold another coordinator's modules, counts and connections into this one
+    ///
+    /// Meant for aggregating per-worker coordinators into a fleet-wide view.
+    /// Message, error and operation counts and memory usage are summed,
+    /// connections are unioned, and a strength present on both sides is
+    /// combined according to `merge_strength_policy`. Averages are weighted by
+    /// how many coordinators each side already folded in, so merging N workers
+    /// one by one, or in any grouping, yields their plain mean. Cognitive load
+    /// and last activity take the higher value. `other` is left unchanged.
+    pub fn merge_from(&self, other: &HypergraphCoordinator) -> Result<()> {
+        if std::ptr::eq(self, other) {
+            return Err(anyhow!("Cannot merge a coordinator into itself"));
+        }
+        self.ensure_topology_mutable()?;
+        let other_registry = other.module_registry.read().clone();
+        let other_matrix = other.synergy_matrix.read().clone();
+        let other_perf = other.performance_metrics.read().clone();
+        let other_samples = other.merged_samples.read().clone();
+        
+        let mut registry = self.module_registry.write();
+        for (name, theirs) in other_registry {
+            let version = self.next_version();
+            let ours = registry.entry(name.clone())
+                .or_insert_with(|| self.new_module_metrics(&name));
+            ours.message_count += theirs.message_count;
+            ours.error_count += theirs.error_count;
+            ours.memory_usage += theirs.memory_usage;
+            ours.cognitive_load = ours.cognitive_load.max(theirs.cognitive_load);
+            ours.last_activity = ours.last_activity.max(theirs.last_activity);
+            ours.active_connections.extend(theirs.active_connections);
+            ours.version = version;
+        }
+        drop(registry);
+        
+        let mut synergy_matrix = self.synergy_matrix.write();
+        let mut edge_metadata = self.edge_metadata.write();
+        let now = self.now();
+        let mut merged_samples = self.merged_samples.write();
+        for (pair, strength) in other_matrix {
+            let key = edge_key(&pair.0, &pair.1);
+            let their_samples = other_samples.get(&pair).copied().unwrap_or(1);
+            let samples = match synergy_matrix.get_mut(&pair) {
+                Some(ours) => {
+                    let our_samples = merged_samples.get(&pair).copied().unwrap_or(1);
+                    *ours = match self.settings.merge_strength_policy {
+                        MergeStrengthPolicy::Max => ours.max(strength),
+                        MergeStrengthPolicy::Average => {
+                            (*ours * our_samples as f64 + strength * their_samples as f64)
+                                / (our_samples + their_samples) as f64
+                        }
+                    };
+                    our_samples + their_samples
+                }
+                None => {
+                    synergy_matrix.insert(pair.clone(), strength);
+                    their_samples
+                }
+            };
+            merged_samples.insert(pair, samples);
+            edge_metadata.entry(key).or_insert_with(|| EdgeMetadata {
+                last_reinforced: now,
+                expires_at: None,
+                idle_ttl: None,
+                operation_counts: IndexMap::new(),
+            });
+        }
+        drop(merged_samples);
+        drop(edge_metadata);
+        drop(synergy_matrix);
+        
+        let mut perf = self.performance_metrics.write();
+        let total = perf.total_operations + other_perf.total_operations;
+        if total > 0 {
+            // Weight each side's average latency by its operation count
+            let weighted = perf.average_response_time.as_secs_f64() * perf.total_operations as f64
+                + other_perf.average_response_time.as_secs_f64() * other_perf.total_operations as f64;
+            perf.average_response_time = Duration::from_secs_f64(weighted / total as f64);
+        }
+        perf.total_operations = total;
+        drop(perf);
+        *self.audit_cache.write() = None;
+        Ok(())
+    }
+
+    /// Persist the module registry, synergy matrix and recent audit history as JSON
+    ///
+    /// Counts and connection strengths round-trip exactly through
+    /// `load_snapshot`; timestamps are not saved.
+    pub fn save_snapshot(&self, path: &std::path::Path) -> Result<()> {
+        let sorted = |set: &HashSet<String>| {
+            let mut items: Vec<String> = set.iter().cloned().collect();
+            items.sort();
+            items
+        };
+        let modules = self.module_registry.read()
+            .values()
+            .map(|metrics| PersistedModule {
+                name: metrics.name.clone(),
+                active_connections: sorted(&metrics.active_connections),
+                message_count: metrics.message_count,
+                error_count: metrics.error_count,
+                memory_usage: metrics.memory_usage,
+                cognitive_load: metrics.cognitive_load,
+                declared_operations: metrics.declared_operations.as_ref().map(sorted),
+                undeclared_operations: sorted(&metrics.undeclared_operations),
+                lifecycle: metrics.lifecycle,
+                gauges: metrics.gauges.clone(),
+            })
+            .collect();
+        let mut synergy_matrix: Vec<(String, String, f64)> = self.synergy_matrix.read()
+            .iter()
+            .map(|((a, b), strength)| (a.clone(), b.clone(), *strength))
+            .collect();
+        synergy_matrix.sort_by(|x, y| (&x.0, &x.1).cmp(&(&y.0, &y.1)));
+        let audit_history = {
+            let history = self.audit_history.read();
+            let skip = history.len().saturating_sub(PERSISTED_AUDIT_HISTORY);
+            history[skip..].iter().map(AuditSummary::from).collect()
+        };
+        
+        let state = PersistedState {
+            schema_version: PERSISTED_SCHEMA_VERSION,
+            modules,
+            synergy_matrix,
+            audit_history,
+        };
+        let content = serde_json::to_string_pretty(&state)?;
+        std::fs::write(path, content)
+            .map_err(|err| anyhow!("Failed to write hypergraph snapshot '{}': {}", path.display(), err))
+    }
+
+    /// Replace the registry, synergy matrix and audit history with a snapshot saved by `save_snapshot`
+    ///
+    /// Activity and check times are rebased to now. Snapshots written with a
+    /// different schema version are rejected without touching current state.
+    pub fn load_snapshot(&self, path: &std::path::Path) -> Result<()> {
+        self.ensure_topology_mutable()?;
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read hypergraph snapshot '{}': {}", path.display(), err))?;
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|err| anyhow!("Invalid hypergraph snapshot '{}': {}", path.display(), err))?;
+        match value.get("schema_version").and_then(|version| version.as_u64()) {
+            Some(version) if version == PERSISTED_SCHEMA_VERSION as u64 => {}
+            Some(version) => {
+                return Err(anyhow!(
+                    "Unsupported hypergraph snapshot schema version {} in '{}' (expected {})",
+                    version, path.display(), PERSISTED_SCHEMA_VERSION
+                ));
+            }
+            None => {
+                return Err(anyhow!(
+                    "Hypergraph snapshot '{}' has no schema version (expected {})",
+                    path.display(), PERSISTED_SCHEMA_VERSION
+                ));
+            }
+        }
+        let state: PersistedState = serde_json::from_value(value)
+            .map_err(|err| anyhow!("Invalid hypergraph snapshot '{}': {}", path.display(), err))?;
+        
+        let now = self.now();
+        let mut registry = self.module_registry.write();
+        let mut synergy_matrix = self.synergy_matrix.write();
+        let mut edge_metadata = self.edge_metadata.write();
+        
+        registry.clear();
+        for module in state.modules {
+            let metrics = ModuleMetrics {
+                name: module.name.clone(),
+                active_connections: module.active_connections.into_iter().collect(),
+                message_count: module.message_count,
+                error_count: module.error_count,
+                last_activity: now,
+                memory_usage: module.memory_usage,
+                cognitive_load: module.cognitive_load,
+                declared_operations: module.declared_operations.map(|ops| ops.into_iter().collect()),
+                undeclared_operations: module.undeclared_operations.into_iter().collect(),
+                lifecycle: module.lifecycle,
+                gauges: module.gauges,
+                recent_errors: VecDeque::new(),
+                version: self.next_version(),
+            };
+            registry.insert(module.name, metrics);
+        }
+        
+        synergy_matrix.clear();
+        edge_metadata.clear();
+        for (a, b, strength) in state.synergy_matrix {
+            edge_metadata.entry(edge_key(&a, &b)).or_insert_with(|| EdgeMetadata {
+                last_reinforced: now,
+                expires_at: None,
+                idle_ttl: None,
+                operation_counts: IndexMap::new(),
+            });
+            synergy_matrix.insert((a, b), strength);
+        }
+        drop(edge_metadata);
+        drop(synergy_matrix);
+        drop(registry);
+        
+        *self.audit_history.write() = state.audit_history.into_iter()
+            .map(|summary| ModuleAudit {
+                module_name: summary.module_name,
+                status: summary.status,
+                synergy_score: summary.synergy_score,
+                confidence: summary.confidence,
+                hypergraph_connections: summary.hypergraph_connections,
+                last_checked: now,
+                issues: summary.issues,
+                recommendations: summary.recommendations,
+                gauges: summary.gauges,
+            })
+            .collect();
+        *self.audit_cache.write() = None;
+        
+        log::info!("Loaded hypergraph snapshot from '{}'", path.display());
+        Ok(())
+    }
+
+    /// Capture the current topology and per-module scores
+    pub fn snapshot(&self) -> HypergraphSnapshot {
+        let registry = self.module_registry.read();
+        let synergy_matrix = self.synergy_matrix.read();
+        
+        let modules: IndexMap<String, ModuleSnapshot> = registry.iter()
+            .map(|(name, metrics)| {
+                let mut connections: Vec<String> = metrics.active_connections.iter().cloned().collect();
+                connections.sort();
+                let snapshot = ModuleSnapshot {
+                    message_count: metrics.message_count,
+                    error_count: metrics.error_count,
+                    synergy_score: self.calculate_synergy_score(name, &synergy_matrix),
+                    connections,
+                };
+                (name.clone(), snapshot)
+            })
+            .collect();
+        
+        let synergy_coefficient = self.weighted_synergy_coefficient(
+            modules.iter().map(|(name, m)| (name.as_str(), m.synergy_score))
+        );
+        
+        HypergraphSnapshot {
+            modules,
+            synergy_coefficient,
+        }
+    }
+
+    /// Audit only the modules that changed since `marker`, plus a marker for the next call
+    ///
+    /// Pass `ExportMarker::default()` to export every module.
+    pub fn export_changed_since(&self, marker: ExportMarker) -> Result<(Vec<ModuleAudit>, ExportMarker)> {
+        let next_marker = ExportMarker(self.change_counter.load(Ordering::SeqCst));
+        let changed: HashSet<String> = self.module_registry.read()
+            .iter()
+            .filter(|(_, metrics)| metrics.version > marker.0)
+            .map(|(name, _)| name.clone())
+            .collect();
+        
+        let audits = self.audit_core_modules()?
+            .into_iter()
+            .filter(|audit| changed.contains(&audit.module_name))
+            .collect();
+        
+        Ok((audits, next_marker))
+    }
 
-/// Manages hypergraph synergy across all core modules
-pub struct HypergraphCoordinator {
-    config: GlobalConfig,
-    module_registry: RwLock<IndexMap<String, ModuleMetrics>>,
-    synergy_matrix: RwLock<HashMap<(String, String), f64>>,
-    audit_history: RwLock<Vec<ModuleAudit>>,
-    performance_metrics: RwLock<PerformanceMetrics>,
-}
+    /// Total recorded time per operation type across all modules
+    pub fn operation_time_breakdown(&self) -> HashMap<String, Duration> {
+        self.operation_times.read().clone()
+    }
 
-#[derive(Debug, Clone)]
-struct ModuleMetrics {
-    name: String,
-    active_connections: HashSet<String>,
-    message_count: u64,
-    error_count: u64,
-    last_activity: Instant,
-    memory_usage: usize,
-    cognitive_load: f64,
-}
+    /// Latency count, range and percentile estimates for one operation type
+    ///
+    /// Aliased operation types are reported under their canonical name.
+    pub fn latency_stats(&self, operation_type: &str) -> Option<LatencyStats> {
+        let operation_type = self.canonical_operation(operation_type);
+        self.operation_latencies.read()
+            .get(&operation_type)
+            .map(LatencyTracker::stats)
+    }
 
-#[derive(Debug, Clone, Default)]
-pub struct PerformanceMetrics {
-    pub total_operations: u64,
-    pub average_response_time: Duration,
-    pub memory_efficiency: f64,
-    pub synergy_coefficient: f64,
-}
+    /// Fold operation type `alias` into `canonical` for load factors and breakdowns
+    pub fn set_op_alias(&self, alias: &str, canonical: &str) -> Result<()> {
+        if alias == canonical {
+            return Err(anyhow!("Operation type '{}' cannot alias itself", alias));
+        }
+        let mut aliases = self.op_aliases.write();
+        if aliases.contains_key(canonical) {
+            return Err(anyhow!("'{}' is itself an alias and cannot be a canonical operation type", canonical));
+        }
+        // Aliases resolve in a single lookup, so chains would split counts
+        if aliases.values().any(|target| target == alias) {
+            return Err(anyhow!("'{}' is the canonical operation type of another alias and cannot be an alias", alias));
+        }
+        aliases.insert(alias.to_string(), canonical.to_string());
+        Ok(())
+    }
 
-impl HypergraphCoordinator {
-    /// Create a new hypergraph coordinator instance
-    pub fn new(config: GlobalConfig) -> Self {
-        Self {
-            config,
-            module_registry: RwLock::new(IndexMap::new()),
-            synergy_matrix: RwLock::new(HashMap::new()),
-            audit_history: RwLock::new(Vec::new()),
-            performance_metrics: RwLock::new(PerformanceMetrics::default()),
+    /// Canonical name for `operation_type`, or the name itself if it is not an alias
+    fn canonical_operation(&self, operation_type: &str) -> String {
+        self.op_aliases.read()
+            .get(operation_type)
+            .cloned()
+            .unwrap_or_else(|| operation_type.to_string())
+    }
+
+    /// Override how long `module_name` may stay idle before it is flagged as stale
+    pub fn set_staleness_window(&self, module_name: &str, window: Duration) -> Result<()> {
+        if !self.module_registry.read().contains_key(module_name) {
+            return Err(anyhow!("Module '{}' is not registered", module_name));
         }
+        self.staleness_windows.write().insert(module_name.to_string(), window);
+        Ok(())
     }
 
-    /// Register a core module for hypergraph tracking
-    pub fn register_module(&self, module_name: &str) -> Result<()> {
-        let mut registry = self.module_registry.write();
+    /// Declare that `module_name` relies on `dependency` to function
+    ///
+    /// A module whose dependency is Critical is downgraded to at least Warning
+    /// in the audit, regardless of its own metrics.
+    pub fn declare_dependency(&self, module_name: &str, dependency: &str) -> Result<()> {
+        if module_name == dependency {
+            return Err(anyhow!("Module '{}' cannot depend on itself", module_name));
+        }
+        {
+            let registry = self.module_registry.read();
+            for name in [module_name, dependency] {
+                if !registry.contains_key(name) {
+                    return Err(anyhow!("Module '{}' is not registered", name));
+                }
+            }
+        }
         
-        let metrics = ModuleMetrics {
-            name: module_name.to_string(),
-            active_connections: HashSet::new(),
-            message_count: 0,
-            error_count: 0,
-            last_activity: Instant::now(),
-            memory_usage: 0,
-            cognitive_load: 0.0,
-        };
+        let mut dependencies = self.dependencies.write();
+        let declared = dependencies.entry(module_name.to_string()).or_default();
+        if !declared.iter().any(|d| d == dependency) {
+            declared.push(dependency.to_string());
+        }
+        Ok(())
+    }
+
+    /// Modules ordered so every module comes after the modules it depends on
+    ///
+    /// Independent modules keep their registration order. Errors if the
+    /// declared dependencies contain a cycle.
+    pub fn startup_order(&self) -> Result<Vec<String>> {
+        let modules: Vec<String> = self.module_registry.read().keys().cloned().collect();
+        let dependencies = self.dependencies.read();
         
-        registry.insert(module_name.to_string(), metrics);
+        let mut remaining: IndexMap<&str, usize> = modules.iter()
+            .map(|name| {
+                let pending = dependencies.get(name).map_or(0, |deps| deps.len());
+                (name.as_str(), pending)
+            })
+            .collect();
+        let mut order = Vec::with_capacity(modules.len());
         
-        log::info!("Registered module '{}' for hypergraph coordination", module_name);
+        while !remaining.is_empty() {
+            let Some(ready) = remaining.iter()
+                .find(|(_, pending)| **pending == 0)
+                .map(|(name, _)| name.to_string()) else {
+                let cycle: Vec<&str> = remaining.keys().copied().collect();
+                return Err(anyhow!("Dependency cycle among modules: {}", cycle.join(", ")));
+            };
+            
+            remaining.shift_remove(ready.as_str());
+            for (name, pending) in remaining.iter_mut() {
+                if dependencies.get(*name).is_some_and(|deps| deps.contains(&ready)) {
+                    *pending -= 1;
+                }
+            }
+            order.push(ready);
+        }
+        
+        Ok(order)
+    }
+
+    /// Enable or disable auto-healing for one module, overriding `enable_auto_healing`
+    pub fn set_auto_healing(&self, module_name: &str, enabled: bool) -> Result<()> {
+        if !self.module_registry.read().contains_key(module_name) {
+            return Err(anyhow!("Module '{}' is not registered", module_name));
+        }
+        self.auto_healing_overrides.write().insert(module_name.to_string(), enabled);
         Ok(())
     }
 
-    /// Establish a synergy connection between two modules
-    pub fn establish_connection(&self, module_a: &str, module_b: &str, strength: f64) -> Result<()> {
+    /// Per-module auto-healing override, if one was set
+    pub fn auto_healing_override(&self, module_name: &str) -> Option<bool> {
+        self.auto_healing_overrides.read().get(module_name).copied()
+    }
+
+    /// Whether any module has auto-healing explicitly enabled
+    pub fn has_auto_healing_opt_in(&self) -> bool {
+        self.auto_healing_overrides.read().values().any(|enabled| *enabled)
+    }
+
+    /// Set a custom gauge on `module_name`, replacing any previous value for `key`
+    pub fn set_gauge(&self, module_name: &str, key: &str, value: f64) -> Result<()> {
         let mut registry = self.module_registry.write();
-        let mut synergy_matrix = self.synergy_matrix.write();
-        
-        // Update module connections
-        if let Some(metrics_a) = registry.get_mut(module_a) {
-            metrics_a.active_connections.insert(module_b.to_string());
+        let metrics = registry.get_mut(module_name)
+            .ok_or_else(|| anyhow!("Module '{}' is not registered", module_name))?;
+        metrics.gauges.insert(key.to_string(), value);
+        metrics.version = self.next_version();
+        Ok(())
+    }
+
+    /// Custom gauges of every module, in registration order
+    pub fn module_gauges(&self) -> Vec<(String, IndexMap<String, f64>)> {
+        self.module_registry.read()
+            .iter()
+            .filter(|(_, metrics)| !metrics.gauges.is_empty())
+            .map(|(name, metrics)| (name.clone(), metrics.gauges.clone()))
+            .collect()
+    }
+
+    /// Set how much `module_name` counts toward the overall synergy coefficient
+    ///
+    /// Modules default to a weight of 1.0; a weight of 0.0 excludes the module.
+    pub fn set_module_weight(&self, module_name: &str, weight: f64) -> Result<()> {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(anyhow!("Module weight must be a non-negative finite number, got {}", weight));
+        }
+        if !self.module_registry.read().contains_key(module_name) {
+            return Err(anyhow!("Module '{}' is not registered", module_name));
+        }
+        self.module_weights.write().insert(module_name.to_string(), weight);
+        Ok(())
+    }
+
+    /// Weighted average of per-module synergy scores
+    fn weighted_synergy_coefficient<'a>(&self, scores: impl Iterator<Item = (&'a str, f64)>) -> f64 {
+        let weights = self.module_weights.read();
+        let (weighted_sum, total_weight) = scores.fold((0.0, 0.0), |(sum, total), (module, score)| {
+            let weight = weights.get(module).copied().unwrap_or(1.0);
+            (sum + score * weight, total + weight)
+        });
+        if total_weight > 0.0 {
+            weighted_sum / total_weight
+        } else {
+            0.0
+        }
+    }
+
+    /// Whether `module_name` is registered and has recorded activity within its staleness window
+    pub fn is_recently_active(&self, module_name: &str) -> bool {
+        let last_activity = match self.module_registry.read().get(module_name) {
+            Some(metrics) => metrics.last_activity,
+            None => return false,
+        };
+        self.now().duration_since(last_activity) <= self.staleness_window(module_name)
+    }
+
+    /// Idle time after which `module_name` is considered stale
+    ///
+    /// A window set with `set_staleness_window` wins over a configured
+    /// `stale_activity_overrides` entry, which wins over `stale_activity_seconds`.
+    fn staleness_window(&self, module_name: &str) -> Duration {
+        if let Some(window) = self.staleness_windows.read().get(module_name) {
+            return *window;
         }
-        if let Some(metrics_b) = registry.get_mut(module_b) {
-            metrics_b.active_connections.insert(module_a.to_string());
+        let seconds = self.settings.stale_activity_overrides.get(module_name)
+            .copied()
+            .unwrap_or(self.settings.stale_activity_seconds);
+        Duration::from_secs(seconds)
+    }
+
+    /// Seed a module's counters from historical data without replaying events
+    ///
+    /// The change in `message_count` is also applied to the global operation
+    /// total so lifetime error rates stay consistent.
+    pub fn backfill(
+        &self,
+        module_name: &str,
+        message_count: u64,
+        error_count: u64,
+        memory_usage: usize,
+        mode: BackfillMode,
+    ) -> Result<()> {
+        let mut registry = self.module_registry.write();
+        let metrics = registry.get_mut(module_name)
+            .ok_or_else(|| anyhow!("Module '{}' is not registered", module_name))?;
+        
+        let previous_messages = metrics.message_count;
+        match mode {
+            BackfillMode::Additive => {
+                metrics.message_count += message_count;
+                metrics.error_count += error_count;
+                metrics.memory_usage += memory_usage;
+            }
+            BackfillMode::Absolute => {
+                metrics.message_count = message_count;
+                metrics.error_count = error_count;
+                metrics.memory_usage = memory_usage;
+            }
         }
+        metrics.version = self.next_version();
+        let new_messages = metrics.message_count;
+        drop(registry);
         
-        // Update synergy matrix
-        synergy_matrix.insert((module_a.to_string(), module_b.to_string()), strength);
-        synergy_matrix.insert((module_b.to_string(), module_a.to_string()), strength);
+        let mut perf = self.performance_metrics.write();
+        perf.total_operations = (perf.total_operations + new_messages).saturating_sub(previous_messages);
         
-        log::debug!("Established connection: {} <-> {} (strength: {:.2})", 
-                   module_a, module_b, strength);
+        log::info!("Backfilled module '{}' ({:?}): {} messages, {} errors", 
+                   module_name, mode, message_count, error_count);
         Ok(())
     }
 
-    /// Record module activity for hypergraph analysis
-    pub fn record_activity(&self, module_name: &str, operation_type: &str, duration: Duration) -> Result<()> {
+    /// Report `module_name`'s current memory footprint in bytes
+    ///
+    /// For modules that cannot provide a sampler; a sampler installed with
+    /// `set_memory_sampler` overwrites this value at the next audit.
+    pub fn update_memory_usage(&self, module_name: &str, bytes: usize) -> Result<()> {
         let mut registry = self.module_registry.write();
+        let metrics = registry.get_mut(module_name)
+            .ok_or_else(|| anyhow!("Module '{}' is not registered", module_name))?;
+        if metrics.memory_usage != bytes {
+            metrics.memory_usage = bytes;
+            metrics.version = self.next_version();
+        }
+        Ok(())
+    }
+
+    /// Install a callback that reports `module_name`'s memory footprint at each audit
+    pub fn set_memory_sampler(
+        &self,
+        module_name: &str,
+        sampler: impl Fn() -> usize + Send + Sync + 'static,
+    ) -> Result<()> {
+        if !self.module_registry.read().contains_key(module_name) {
+            return Err(anyhow!("Module '{}' is not registered", module_name));
+        }
+        self.memory_samplers.write().insert(module_name.to_string(), Arc::new(sampler));
+        Ok(())
+    }
+
+    /// Refresh `memory_usage` from the installed samplers
+    ///
+    /// A sampler that panics is logged and the module keeps its previous value.
+    fn sample_memory_usage(&self) -> Vec<String> {
+        let samplers: Vec<(String, MemorySampler)> = self.memory_samplers.read()
+            .iter()
+            .map(|(module, sampler)| (module.clone(), sampler.clone()))
+            .collect();
         
-        if let Some(metrics) = registry.get_mut(module_name) {
-            metrics.message_count += 1;
-            metrics.last_activity = Instant::now();
-            
-            // Update cognitive load based on operation type and duration
-            let load_factor = match operation_type {
-                "llm_completion" => 0.8,
-                "embedding" => 0.5,
-                "session_management" => 0.3,
-                "rag_query" => 0.6,
-                "hypergraph_update" => 0.9,
-                _ => 0.4,
-            };
-            
-            metrics.cognitive_load = (metrics.cognitive_load * 0.9) + 
-                                   (load_factor * duration.as_secs_f64() * 0.1);
+        let mut failures = Vec::new();
+        for (module_name, sampler) in samplers {
+            let name = format!("memory sampler for '{}'", module_name);
+            match guard_callback(&name, || sampler()) {
+                Ok(bytes) => {
+                    if let Some(metrics) = self.module_registry.write().get_mut(&module_name) {
+                        if metrics.memory_usage != bytes {
+                            metrics.memory_usage = bytes;
+                            metrics.version = self.next_version();
+                        }
+                    }
+                }
+                Err(err) => failures.push(err.to_string()),
+            }
         }
         
-        // Update global performance metrics
-        let mut perf = self.performance_metrics.write();
-        perf.total_operations += 1;
+        failures
+    }
+
+    /// Record the lifecycle phase of a module
+    pub fn set_lifecycle(&self, module_name: &str, lifecycle: ModuleLifecycle) -> Result<()> {
+        let mut registry = self.module_registry.write();
+        let metrics = registry.get_mut(module_name)
+            .ok_or_else(|| anyhow!("Module '{}' is not registered", module_name))?;
+        metrics.lifecycle = lifecycle;
+        metrics.version = self.next_version();
+        log::info!("Module '{}' lifecycle: {:?}", module_name, lifecycle);
+        Ok(())
+    }
+
+    /// Single routing signal for whether `module_name` can take new work
+    ///
+    /// Health comes from the most recent audit; an audit is run first if the
+    /// module has never been audited.
+    pub fn module_readiness(&self, module_name: &str) -> Result<Readiness> {
+        let lifecycle = self.module_registry.read()
+            .get(module_name)
+            .map(|metrics| metrics.lifecycle)
+            .ok_or_else(|| anyhow!("Module '{}' is not registered", module_name))?;
         
-        // Exponential moving average for response time
-        let alpha = 0.1;
-        let current_avg = perf.average_response_time.as_secs_f64();
-        let new_avg = current_avg * (1.0 - alpha) + duration.as_secs_f64() * alpha;
-        perf.average_response_time = Duration::from_secs_f64(new_avg);
+        let cached = self.last_statuses.read().get(module_name).cloned();
+        let status = match cached {
+            Some(status) => status,
+            None => self.audit_core_modules()?
+                .into_iter()
+                .find(|audit| audit.module_name == module_name)
+                .map(|audit| audit.status)
+                .ok_or_else(|| anyhow!("Module '{}' is not registered", module_name))?,
+        };
+        
+        let readiness = match (lifecycle, status) {
+            (ModuleLifecycle::Stopped, _) => Readiness::Excluded,
+            (ModuleLifecycle::Starting | ModuleLifecycle::Draining, _) => Readiness::NotReady,
+            (ModuleLifecycle::Running, ModuleStatus::Critical) => Readiness::NotReady,
+            (ModuleLifecycle::Running, ModuleStatus::Warning | ModuleStatus::Disconnected) => Readiness::Degraded,
+            (ModuleLifecycle::Running, ModuleStatus::Healthy) => Readiness::Ready,
+        };
+        Ok(readiness)
+    }
+
+    /// Record a RAG retrieval with its quality signal
+    ///
+    /// Counts as `rag_query` activity on the `rag` module and feeds the rolling
+    /// retrieval quality reported by `retrieval_quality` and the audit.
+    pub fn record_rag_retrieval(&self, top_score: f64, hits: usize, duration: Duration) -> Result<()> {
+        self.record_activity(RAG_MODULE, "rag_query", duration)?;
         
+        let top_score = if top_score.is_finite() { top_score } else { 0.0 };
+        let mut retrievals = self.retrievals.write();
+        retrievals.push_back((top_score, hits));
+        while retrievals.len() > RETRIEVAL_QUALITY_WINDOW {
+            retrievals.pop_front();
+        }
         Ok(())
     }
 
-    /// Record an error for error tracking and module health assessment
-    pub fn record_error(&self, module_name: &str, error: &str) -> Result<()> {
+    /// Quality of the most recent RAG retrievals, if any were recorded
+    pub fn retrieval_quality(&self) -> Option<RetrievalQuality> {
+        let retrievals = self.retrievals.read();
+        if retrievals.is_empty() {
+            return None;
+        }
+        let samples = retrievals.len();
+        let count = samples as f64;
+        Some(RetrievalQuality {
+            samples,
+            average_top_score: retrievals.iter().map(|(score, _)| score).sum::<f64>() / count,
+            average_hits: retrievals.iter().map(|(_, hits)| *hits as f64).sum::<f64>() / count,
+            empty_rate: retrievals.iter().filter(|(_, hits)| *hits == 0).count() as f64 / count,
+        })
+    }
+
+    /// Drive `module_name` into a specific unhealthy state for chaos testing
+    ///
+    /// Failures go through the same bookkeeping as real activity, so alerting
+    /// and auto-healing see them exactly as they would see production faults.
+    #[cfg(feature = "chaos")]
+    pub fn inject_failure(&self, module_name: &str, failure: FailureKind) -> Result<()> {
+        if !self.module_registry.read().contains_key(module_name) {
+            return Err(anyhow!("Module '{}' is not registered", module_name));
+        }
+        log::warn!("Injecting {:?} into module '{}'", failure, module_name);
+        
+        match failure {
+            FailureKind::BurstErrors(count) => {
+                for _ in 0..count {
+                    self.record_error(module_name, "injected failure")?;
+                }
+            }
+            FailureKind::Disconnect => {
+                let mut registry = self.module_registry.write();
+                let mut synergy_matrix = self.synergy_matrix.write();
+                let mut edge_metadata = self.edge_metadata.write();
+                let peers: Vec<String> = registry[module_name].active_connections.iter().cloned().collect();
+                for peer in peers {
+                    remove_edge(&mut registry, &mut synergy_matrix, module_name, &peer, self.next_version());
+                    edge_metadata.remove(&edge_key(module_name, &peer));
+                }
+            }
+            FailureKind::HighLatency(duration) => {
+                self.record_activity(module_name, "injected_latency", duration)?;
+            }
+            FailureKind::LoadSpike(load) => {
+                if let Some(metrics) = self.module_registry.write().get_mut(module_name) {
+                    metrics.cognitive_load = load;
+                    metrics.version = self.next_version();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Clear a module's error state so it can recover on the next audit
+    ///
+    /// Zeroes `error_count` and removes the module's errors from the rolling
+    /// metrics window; activity counters and connections are untouched.
+    pub fn clear_errors(&self, module_name: &str) -> Result<()> {
         let mut registry = self.module_registry.write();
+        let metrics = registry.get_mut(module_name)
+            .ok_or_else(|| anyhow!("Module '{}' is not registered", module_name))?;
+        metrics.error_count = 0;
+        metrics.version = self.next_version();
+        drop(registry);
         
-        if let Some(metrics) = registry.get_mut(module_name) {
-            metrics.error_count += 1;
-            log::warn!("Module '{}' error: {}", module_name, error);
+        let mut window = self.metrics_window.write();
+        for bucket in window.iter_mut() {
+            if let Some(errors) = bucket.module_errors.remove(module_name) {
+                bucket.errors = bucket.errors.saturating_sub(errors);
+            }
+        }
+        
+        log::info!("Cleared error state for module '{}'", module_name);
+        Ok(())
+    }
+
+    /// Zero the message and error counts of `module_name`, or of every module with `None`
+    ///
+    /// Connections and strengths are kept, so rates start over from a clean
+    /// signal, for instance after a fix ships. With `reset_load` the cognitive
+    /// load is zeroed too. The global operation total drops by the reset
+    /// modules' message counts so the overall error rate stays consistent.
+    pub fn reset_counters(&self, module_name: Option<&str>, reset_load: bool) -> Result<()> {
+        let mut registry = self.module_registry.write();
+        if let Some(name) = module_name {
+            if !registry.contains_key(name) {
+                return Err(anyhow!("Module '{}' is not registered", name));
+            }
+        }
+        
+        let mut cleared_operations = 0;
+        for (name, metrics) in registry.iter_mut() {
+            if module_name.is_some_and(|target| target != name) {
+                continue;
+            }
+            cleared_operations += metrics.message_count;
+            metrics.message_count = 0;
+            metrics.error_count = 0;
+            metrics.recent_errors.clear();
+            if reset_load {
+                metrics.cognitive_load = 0.0;
+            }
+            metrics.version = self.next_version();
+        }
+        drop(registry);
+        
+        let mut perf = self.performance_metrics.write();
+        perf.total_operations = perf.total_operations.saturating_sub(cleared_operations);
+        drop(perf);
+        
+        let mut window = self.metrics_window.write();
+        for bucket in window.iter_mut() {
+            let cleared: u64 = match module_name {
+                Some(name) => bucket.module_errors.remove(name).unwrap_or(0),
+                None => bucket.module_errors.drain().map(|(_, errors)| errors).sum(),
+            };
+            bucket.errors = bucket.errors.saturating_sub(cleared);
         }
+        drop(window);
+        *self.audit_cache.write() = None;
         
+        log::info!("Reset counters for {}", module_name.map_or("all modules".to_string(), |name| format!("module '{}'", name)));
         Ok(())
     }
 
+    /// Audit for the current tick, shared between consumers
+    ///
+    /// The first call in each `audit_interval_seconds` tick runs a full audit;
+    /// later calls in the same tick return the cached result.
+    pub fn current_audit(&self) -> Result<Arc<Vec<ModuleAudit>>> {
+        let now = self.now();
+        let tick = Duration::from_secs(self.settings.audit_interval_seconds);
+        if let Some((computed_at, audits)) = self.audit_cache.read().as_ref() {
+            if now.duration_since(*computed_at) < tick {
+                return Ok(audits.clone());
+            }
+        }
+        
+        let mut cache = self.audit_cache.write();
+        // Another consumer may have refreshed the cache while we waited
+        if let Some((computed_at, audits)) = cache.as_ref() {
+            if now.duration_since(*computed_at) < tick {
+                return Ok(audits.clone());
+            }
+        }
+        let audits = Arc::new(self.audit_core_modules()?);
+        *cache = Some((now, audits.clone()));
+        Ok(audits)
+    }
+
+    /// Names of modules whose current audit status is `status`, in registration order
+    ///
+    /// Runs a fresh audit rather than reading the per-tick `current_audit`
+    /// cache, so the statuses reflect the latest activity.
+    pub fn modules_with_status(&self, status: ModuleStatus) -> Result<Vec<String>> {
+        Ok(self.audit_core_modules()?
+            .iter()
+            .filter(|audit| audit.status == status)
+            .map(|audit| audit.module_name.clone())
+            .collect())
+    }
+
     /// Perform comprehensive audit of all core modules
     pub fn audit_core_modules(&self) -> Result<Vec<ModuleAudit>> {
+        self.expire_edges();
+        let audit_errors = self.sample_memory_usage();
+        
         let registry = self.module_registry.read();
         let synergy_matrix = self.synergy_matrix.read();
+        let now = self.now();
         let mut audits = Vec::new();
         
         for (module_name, metrics) in registry.iter() {
@@ -175,15 +3130,36 @@ impl HypergraphCoordinator {
             let synergy_score = self.calculate_synergy_score(module_name, &synergy_matrix);
             
             // Determine module status based on various factors
-            let status = if metrics.error_count > 10 {
-                issues.push("High error count detected".to_string());
-                recommendations.push("Review error handling and add circuit breakers".to_string());
-                ModuleStatus::Critical
+            let mut status = if metrics.error_count > 10 {
+                match metrics.recent_errors.back() {
+                    Some((_, latest)) => issues.push(format!("High error count detected (latest: {})", latest)),
+                    None => issues.push("High error count detected".to_string()),
+                }
+                recommendations.push("Review error handling and gate calls on is_available".to_string());
+                // Too little traffic to tell a broken module from a noisy warmup
+                if metrics.message_count < self.settings.min_operations_for_critical {
+                    issues.push(format!(
+                        "Capped at Warning: only {} operations recorded (Critical needs {})",
+                        metrics.message_count, self.settings.min_operations_for_critical
+                    ));
+                    ModuleStatus::Warning
+                } else {
+                    ModuleStatus::Critical
+                }
             } else if metrics.active_connections.is_empty() {
                 issues.push("Module appears disconnected from hypergraph".to_string());
                 recommendations.push("Establish connections with related modules".to_string());
+                // Busy modules without edges point at missing instrumentation
+                // rather than a module that is simply idle
+                if metrics.message_count >= self.settings.active_unconnected_threshold {
+                    issues.push(format!(
+                        "Active but unconnected: {} operations recorded without any connections",
+                        metrics.message_count
+                    ));
+                    recommendations.push("Check that establish_connection is called for this module's collaborators".to_string());
+                }
                 ModuleStatus::Disconnected
-            } else if synergy_score < 0.5 {
+            } else if below_threshold(synergy_score, 0.5, self.settings.comparison_epsilon) {
                 issues.push("Low synergy score with other modules".to_string());
                 recommendations.push("Improve inter-module communication patterns".to_string());
                 ModuleStatus::Warning
@@ -192,40 +3168,302 @@ impl HypergraphCoordinator {
             };
             
             // Check for stale activity
-            if metrics.last_activity.elapsed() > Duration::from_secs(300) {
+            if now.duration_since(metrics.last_activity) > self.staleness_window(module_name) {
                 issues.push("No recent activity detected".to_string());
                 recommendations.push("Verify module is active and responding".to_string());
             }
             
             // Check cognitive load
-            if metrics.cognitive_load > 0.9 {
+            if above_threshold(metrics.cognitive_load, 0.9, self.settings.comparison_epsilon) {
                 issues.push("High cognitive load detected".to_string());
                 recommendations.push("Consider load balancing or resource optimization".to_string());
             }
             
+            // Check for operations that started but never finished
+            if self.settings.stuck_operation_seconds > 0 {
+                let threshold = Duration::from_secs(self.settings.stuck_operation_seconds);
+                let stuck = self.in_flight.read().get(module_name.as_str())
+                    .map_or(0, |operations| {
+                        operations.values().filter(|started| now.duration_since(**started) > threshold).count()
+                    });
+                if stuck > 0 {
+                    issues.push(format!(
+                        "Potentially stuck: {} operations in flight for over {}s", stuck, threshold.as_secs()
+                    ));
+                    recommendations.push("Check the module for hung calls or missing timeouts".to_string());
+                    if status == ModuleStatus::Healthy {
+                        status = ModuleStatus::Warning;
+                    }
+                }
+            }
+            
+            // Check for hubs that every request funnels through
+            if self.is_over_connected(module_name, metrics) {
+                issues.push(format!(
+                    "Over-connected: {} connections (warning above {})",
+                    metrics.active_connections.len(), self.settings.max_connections_before_warn
+                ));
+                recommendations.push("Consider load balancing by splitting responsibilities across modules".to_string());
+                if status == ModuleStatus::Healthy {
+                    status = ModuleStatus::Warning;
+                }
+            }
+            
+            // Check for a burst of new errors below the critical threshold
+            let spike_window = Duration::from_secs(self.settings.error_spike_window_seconds);
+            let recent_errors = self.module_errors_in_window(module_name, spike_window);
+            if recent_errors > self.settings.error_spike_threshold {
+                issues.push(format!(
+                    "Error spike: {} errors in the last {}s", recent_errors, spike_window.as_secs()
+                ));
+                recommendations.push("Check recent deployments or upstream dependencies".to_string());
+            }
+            
+            // Check retrieval effectiveness for the RAG module
+            if module_name == RAG_MODULE {
+                if let Some(quality) = self.retrieval_quality() {
+                    if below_threshold(quality.average_top_score, self.settings.min_retrieval_quality, self.settings.comparison_epsilon) {
+                        issues.push(format!(
+                            "Low retrieval quality (average top score {:.2} over {} retrievals)",
+                            quality.average_top_score, quality.samples
+                        ));
+                        recommendations.push("Review chunking, embeddings, or reranking for the RAG index".to_string());
+                    }
+                }
+            }
+            
+            if let CircuitState::Open { since } = self.circuit_state(module_name) {
+                issues.push(format!(
+                    "Circuit breaker open for {}s; calls are being skipped",
+                    now.duration_since(since).as_secs()
+                ));
+            }
+            
+            // Check for instrumentation using operation types the module never declared
+            if !metrics.undeclared_operations.is_empty() {
+                let mut undeclared: Vec<&str> = metrics.undeclared_operations.iter().map(|op| op.as_str()).collect();
+                undeclared.sort();
+                issues.push(format!("Recorded undeclared operation types: {}", undeclared.join(", ")));
+                recommendations.push("Check instrumentation is using the correct module name".to_string());
+            }
+            
             let audit = ModuleAudit {
                 module_name: module_name.clone(),
                 status,
                 synergy_score,
+                confidence: sample_confidence(metrics.message_count),
                 hypergraph_connections: metrics.active_connections.len(),
-                last_checked: Instant::now(),
+                last_checked: now,
                 issues,
                 recommendations,
+                gauges: metrics.gauges.clone(),
             };
             
             audits.push(audit);
         }
         
-        // Store audit history
-        let mut history = self.audit_history.write();
-        history.extend(audits.clone());
-        
-        // Keep only last 100 audits per module
-        if history.len() > 1000 {
-            history.drain(0..500);
+        flag_unhealthy_dependencies(&mut audits, &registry, &synergy_matrix);
+        propagate_dependency_health(&mut audits, &self.dependencies.read());
+        flag_isolated_clusters(&mut audits, &connected_components_in(&registry));
+        drop(synergy_matrix);
+        drop(registry);
+        
+        let window = self.settings.synergy_trend_window;
+        if window > 0 {
+            for audit in audits.iter_mut() {
+                let mut scores = self.recent_synergy_scores(&audit.module_name, window - 1);
+                scores.push(audit.synergy_score);
+                if TrendDirection::from_series(&scores) == TrendDirection::Degrading {
+                    audit.recommendations.push(format!(
+                        "Synergy is degrading over the last {} audits", scores.len()
+                    ));
+                }
+            }
+        }
+        
+        let mut last_statuses = self.last_statuses.write();
+        for audit in &audits {
+            let previous = last_statuses.insert(audit.module_name.clone(), audit.status.clone());
+            if previous.as_ref() != Some(&audit.status) {
+                if let Some(old) = previous.clone() {
+                    // Nobody listening is not an error
+                    let _ = self.status_events.send(ModuleStatusEvent {
+                        module: audit.module_name.clone(),
+                        old,
+                        new: audit.status.clone(),
+                        at: now,
+                    });
+                }
+                self.push_event(CoordinatorEvent::StatusChanged {
+                    module: audit.module_name.clone(),
+                    from: previous,
+                    to: audit.status.clone(),
+                });
+            }
+        }
+        drop(last_statuses);
+        
+        // Store audit history
+        self.audit_history.write().extend(audits.clone());
+        self.prune_history();
+        
+        *self.audit_errors.write() = audit_errors;
+        self.sample_performance_series(now);
+        self.sample_matrix_stats();
+        self.autosave_if_due(now);
+        
+        Ok(audits)
+    }
+
+    /// Append an event to the recent-events ring buffer, dropping the oldest when full
+    pub(crate) fn push_event(&self, event: CoordinatorEvent) {
+        let mut events = self.events.write();
+        events.push_back(event);
+        while events.len() > self.settings.event_log_capacity {
+            events.pop_front();
+        }
+    }
+
+    /// Receive an event whenever an audit finds a module's status changed
+    ///
+    /// A module's first audit establishes its baseline and sends nothing.
+    /// Receivers that fall more than `STATUS_EVENT_CAPACITY` events behind
+    /// observe `RecvError::Lagged`.
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<ModuleStatusEvent> {
+        self.status_events.subscribe()
+    }
+
+    /// The last `n` significant events, oldest first
+    pub fn recent_events(&self, n: usize) -> Vec<CoordinatorEvent> {
+        let events = self.events.read();
+        events.iter().skip(events.len().saturating_sub(n)).cloned().collect()
+    }
+
+    /// Direction of `module`'s synergy score over its last `window` audits
+    ///
+    /// Uses the same least-squares slope as the other trends; fewer than three
+    /// retained audits always read as Stable.
+    pub fn synergy_trend(&self, module: &str, window: usize) -> TrendDirection {
+        TrendDirection::from_series(&self.recent_synergy_scores(module, window))
+    }
+
+    /// Synergy scores of `module`'s last `n` retained audits, oldest first
+    fn recent_synergy_scores(&self, module: &str, n: usize) -> Vec<f64> {
+        let history = self.audit_history.read();
+        let mut scores: Vec<f64> = history.iter()
+            .rev()
+            .filter(|audit| audit.module_name == module)
+            .take(n)
+            .map(|audit| audit.synergy_score)
+            .collect();
+        scores.reverse();
+        scores
+    }
+
+    /// Apply the audit history retention policy
+    ///
+    /// Each module keeps at most its last `max_audit_history` audits, and audits
+    /// older than `retain_history_for_seconds` are dropped regardless of count.
+    fn prune_history(&self) {
+        let mut history = self.audit_history.write();
+        let max_per_module = self.settings.max_audit_history;
+        if max_per_module > 0 {
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for audit in history.iter() {
+                *counts.entry(audit.module_name.as_str()).or_default() += 1;
+            }
+            // Number of oldest entries each module still has to shed
+            let mut excess: HashMap<String, usize> = counts
+                .into_iter()
+                .filter(|(_, count)| *count > max_per_module)
+                .map(|(module, count)| (module.to_string(), count - max_per_module))
+                .collect();
+            if !excess.is_empty() {
+                history.retain(|audit| match excess.get_mut(&audit.module_name) {
+                    Some(remaining) if *remaining > 0 => {
+                        *remaining -= 1;
+                        false
+                    }
+                    _ => true,
+                });
+            }
+        }
+        if self.settings.retain_history_for_seconds > 0 {
+            let now = self.now();
+            let retention = Duration::from_secs(self.settings.retain_history_for_seconds);
+            history.retain(|audit| now.duration_since(audit.last_checked) <= retention);
+        }
+    }
+
+    /// Retained audits checked within `since..=until`, oldest first
+    ///
+    /// Pass a module name to follow one module, or `None` for all of them.
+    pub fn audit_history_between(&self, module: Option<&str>, since: Instant, until: Instant) -> Vec<ModuleAudit> {
+        self.audit_history.read()
+            .iter()
+            .filter(|audit| module.is_none_or(|name| audit.module_name == name))
+            .filter(|audit| audit.last_checked >= since && audit.last_checked <= until)
+            .cloned()
+            .collect()
+    }
+
+    /// Sub-steps of the most recent audit that failed without aborting it
+    pub fn last_audit_errors(&self) -> Vec<String> {
+        self.audit_errors.read().clone()
+    }
+
+    /// When the last autosave snapshot was written, if any
+    pub fn last_save_time(&self) -> Option<Instant> {
+        *self.last_save.read()
+    }
+
+    /// Save a snapshot to the configured store once the autosave interval has elapsed
+    ///
+    /// Runs as part of every audit, so the background auditor bounds how much
+    /// state can be lost on a crash. A save failure is logged and retried on
+    /// the next audit rather than failing the audit itself.
+    fn autosave_if_due(&self, now: Instant) {
+        let Some(store) = self.snapshot_store.as_ref() else {
+            return;
+        };
+        let interval = Duration::from_secs(self.settings.autosave_interval_seconds);
+        if interval.is_zero() {
+            return;
+        }
+        if let Some(last_save) = *self.last_save.read() {
+            if now.duration_since(last_save) < interval {
+                return;
+            }
+        }
+        
+        match store.save(&self.snapshot()) {
+            Ok(()) => {
+                *self.last_save.write() = Some(now);
+                log::debug!("Autosaved hypergraph snapshot");
+            }
+            Err(err) => log::warn!("Failed to autosave hypergraph snapshot: {}", err),
+        }
+    }
+
+    /// Rescale all edge strengths so they are comparable across subsystems
+    ///
+    /// Returns the normalized matrix. When `in_place` is true the synergy
+    /// matrix is also overwritten with the normalized values; otherwise the
+    /// live matrix is left untouched. Z-scores fall outside [0, 1], so they
+    /// are only available as a view.
+    pub fn normalize_strengths(&self, mode: NormalizationMode, in_place: bool) -> Result<HashMap<(String, String), f64>> {
+        if !in_place {
+            return Ok(normalized_strengths(&self.synergy_matrix.read(), mode));
+        }
+        if mode != NormalizationMode::MaxScale {
+            return Err(anyhow!("{:?} normalization cannot be applied in place; strengths must stay within [0, 1]", mode));
         }
         
-        Ok(audits)
+        let mut synergy_matrix = self.synergy_matrix.write();
+        let normalized = normalized_strengths(&synergy_matrix, mode);
+        synergy_matrix.clone_from(&normalized);
+        log::debug!("Normalized {} synergy matrix entries ({:?})", normalized.len(), mode);
+        Ok(normalized)
     }
 
     /// Calculate synergy score for a specific module
@@ -240,15 +3478,30 @@ impl HypergraphCoordinator {
                 return 1.0; // Single module case
             }
             
-            // Calculate average connection strength
+            // Calculate average connection strength, containing corrupt edges
+            let mut strength_count = 0.0;
             let total_strength: f64 = metrics.active_connections.iter()
                 .filter_map(|connected_module| {
                     synergy_matrix.get(&(module_name.to_string(), connected_module.clone()))
                 })
+                .filter_map(|strength| {
+                    if strength.is_finite() {
+                        strength_count += 1.0;
+                        return Some(*strength);
+                    }
+                    log::warn!("Non-finite synergy strength on module '{}' ({})", module_name, strength);
+                    match self.settings.non_finite_strengths {
+                        NonFiniteStrengthPolicy::TreatAsZero => {
+                            strength_count += 1.0;
+                            Some(0.0)
+                        }
+                        NonFiniteStrengthPolicy::Skip => None,
+                    }
+                })
                 .sum();
             
-            let average_strength = if connection_count > 0.0 {
-                total_strength / connection_count
+            let average_strength = if strength_count > 0.0 {
+                total_strength / strength_count
             } else {
                 0.0
             };
@@ -257,13 +3510,436 @@ impl HypergraphCoordinator {
             let connectivity_factor = connection_count / max_connections;
             let strength_factor = average_strength;
             
-            // Weight connectivity and strength equally
-            (connectivity_factor * 0.5) + (strength_factor * 0.5)
+            let score = connectivity_factor * self.settings.connectivity_weight
+                + strength_factor * self.settings.strength_weight;
+            if score.is_finite() {
+                score.clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
         } else {
             0.0
         }
     }
 
+    /// Distribute a normalized attention budget across modules, neediest first
+    ///
+    /// Each module's need is an equal mix of cognitive load, error rate, and
+    /// missing synergy, so struggling modules are ranked ahead of healthy ones.
+    /// Allocations sum to 1 whenever at least one module is registered.
+    pub fn attention_allocation(&self) -> Vec<(String, f64)> {
+        let registry = self.module_registry.read();
+        let synergy_matrix = self.synergy_matrix.read();
+        
+        let needs: Vec<(String, f64)> = registry.iter()
+            .map(|(name, metrics)| {
+                let load = metrics.cognitive_load.clamp(0.0, 1.0);
+                let error_rate = (metrics.error_count as f64 / metrics.message_count.max(1) as f64).min(1.0);
+                let missing_synergy = 1.0 - self.calculate_synergy_score(name, &synergy_matrix);
+                (name.clone(), (load + error_rate + missing_synergy) / 3.0)
+            })
+            .collect();
+        
+        let total: f64 = needs.iter().map(|(_, need)| need).sum();
+        let uniform = 1.0 / needs.len().max(1) as f64;
+        let mut allocation: Vec<(String, f64)> = needs.into_iter()
+            .map(|(name, need)| {
+                let share = if total > 0.0 { need / total } else { uniform };
+                (name, share)
+            })
+            .collect();
+        allocation.sort_by(|a, b| b.1.total_cmp(&a.1));
+        allocation
+    }
+
+    /// Declare the topology `topology_coverage` compares the live matrix against
+    pub fn set_expected_topology(&self, declaration: TopologyDeclaration) {
+        *self.expected_topology.write() = Some(declaration);
+    }
+
+    /// Compare the live synergy matrix to the declared topology
+    pub fn topology_coverage(&self) -> Result<Coverage> {
+        let expected_topology = self.expected_topology.read();
+        let declaration = expected_topology.as_ref()
+            .ok_or_else(|| anyhow!("No expected topology has been declared"))?;
+        
+        let expected: std::collections::BTreeSet<(String, String)> = declaration.connections.iter()
+            .map(|(a, b)| edge_key(a, b))
+            .collect();
+        let live: std::collections::BTreeSet<(String, String)> = self.synergy_matrix.read()
+            .keys()
+            .map(|(a, b)| edge_key(a, b))
+            .collect();
+        
+        Ok(Coverage {
+            expected_edges: expected.len(),
+            present: expected.intersection(&live).cloned().collect(),
+            missing: expected.difference(&live).cloned().collect(),
+            extra: live.difference(&expected).cloned().collect(),
+        })
+    }
+
+    /// Modules along the strongest path from `from` to `to`
+    ///
+    /// See `strongest_path_with_strength` for how paths are ranked and when
+    /// there is none.
+    pub fn strongest_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        self.strongest_path_with_strength(from, to, false).map(|(path, _)| path)
+    }
+
+    /// Strongest path between two modules, as the modules along it and its strength
+    ///
+    /// Path strength is the product of its edge strengths (clamped to `[0, 1]`),
+    /// so each extra hop can only weaken it. With `indirect_only` the direct
+    /// edge between the endpoints is ignored. Returns `None` for unknown
+    /// endpoints and when they sit in different components. Identical endpoints
+    /// also give `None`: a path is made of connections and modules never connect
+    /// to themselves, while a zero-hop path of strength 1.0 would make
+    /// `effective_synergy` report perfect synergy of a module with itself.
+    pub fn strongest_path_with_strength(&self, module_a: &str, module_b: &str, indirect_only: bool) -> Option<(Vec<String>, f64)> {
+        let registry = self.module_registry.read();
+        let synergy_matrix = self.synergy_matrix.read();
+        if !registry.contains_key(module_a) || !registry.contains_key(module_b) || module_a == module_b {
+            return None;
+        }
+        
+        // Dijkstra over max-product strength instead of min-sum distance
+        let mut best: HashMap<&str, (f64, Option<&str>)> = HashMap::from([(module_a, (1.0, None))]);
+        let mut visited: HashSet<&str> = HashSet::new();
+        while let Some((current, strength)) = best.iter()
+            .filter(|(name, _)| !visited.contains(*name))
+            .max_by(|a, b| a.1 .0.total_cmp(&b.1 .0))
+            .map(|(name, (strength, _))| (*name, *strength))
+        {
+            if current == module_b {
+                break;
+            }
+            visited.insert(current);
+            
+            for peer in registry[current].active_connections.iter().map(|p| p.as_str()) {
+                if visited.contains(peer) || (indirect_only && current == module_a && peer == module_b) {
+                    continue;
+                }
+                let edge = synergy_matrix.get(&(current.to_string(), peer.to_string()))
+                    .copied()
+                    .filter(|s| s.is_finite())
+                    .unwrap_or(0.0)
+                    .clamp(0.0, 1.0);
+                let candidate = strength * edge;
+                if candidate > 0.0 && best.get(peer).is_none_or(|(known, _)| candidate > *known) {
+                    best.insert(peer, (candidate, Some(current)));
+                }
+            }
+        }
+        
+        let (strength, _) = *best.get(module_b)?;
+        let mut path = vec![module_b.to_string()];
+        let mut cursor = module_b;
+        while let Some((_, Some(previous))) = best.get(cursor) {
+            path.push(previous.to_string());
+            cursor = previous;
+        }
+        path.reverse();
+        Some((path, strength))
+    }
+
+    /// Synergy between two modules accounting for both the direct edge and the best indirect path
+    ///
+    /// The two are combined as independent channels, `1 - (1 - direct) * (1 - indirect)`,
+    /// so a strong common neighbour gives unconnected modules nonzero synergy.
+    pub fn effective_synergy(&self, module_a: &str, module_b: &str) -> f64 {
+        let direct = self.synergy_matrix.read()
+            .get(&(module_a.to_string(), module_b.to_string()))
+            .copied()
+            .filter(|s| s.is_finite())
+            .unwrap_or(0.0)
+            .clamp(0.0, 1.0);
+        let indirect = self.strongest_path_with_strength(module_a, module_b, true)
+            .map_or(0.0, |(_, strength)| strength);
+        1.0 - (1.0 - direct) * (1.0 - indirect)
+    }
+
+    /// Disjoint groups of modules connected directly or transitively
+    ///
+    /// An unconnected module forms a group of its own. Groups are ordered by
+    /// their earliest-registered member.
+    pub fn connected_components(&self) -> Vec<HashSet<String>> {
+        connected_components_in(&self.module_registry.read())
+    }
+
+    /// The `n` strongest module pairs, each pair listed once with its names sorted
+    ///
+    /// Sorted by descending strength, with ties broken alphabetically so the
+    /// result is deterministic.
+    pub fn top_connections(&self, n: usize) -> Vec<(String, String, f64)> {
+        let mut pairs: HashMap<(String, String), f64> = HashMap::new();
+        for ((a, b), strength) in self.synergy_matrix.read().iter() {
+            let entry = pairs.entry(edge_key(a, b)).or_insert(*strength);
+            *entry = entry.max(*strength);
+        }
+        let mut pairs: Vec<(String, String, f64)> = pairs.into_iter()
+            .map(|((a, b), strength)| (a, b, strength))
+            .collect();
+        pairs.sort_by(|x, y| {
+            y.2.total_cmp(&x.2)
+                .then_with(|| x.0.cmp(&y.0))
+                .then_with(|| x.1.cmp(&y.1))
+        });
+        pairs.truncate(n);
+        pairs
+    }
+
+    /// Every module connected to `module_name` with the `module_name -> other` strength
+    ///
+    /// Sorted by descending strength, ties alphabetically. A peer that only
+    /// has a directed edge towards `module_name` is listed at 0.0. Unknown
+    /// modules yield an empty list.
+    pub fn connections_of(&self, module_name: &str) -> Vec<(String, f64)> {
+        let registry = self.module_registry.read();
+        let Some(metrics) = registry.get(module_name) else {
+            return Vec::new();
+        };
+        let synergy_matrix = self.synergy_matrix.read();
+        let mut connections: Vec<(String, f64)> = metrics.active_connections.iter()
+            .map(|other| {
+                let strength = synergy_matrix.get(&(module_name.to_string(), other.clone()))
+                    .copied()
+                    .unwrap_or(0.0);
+                (other.clone(), strength)
+            })
+            .collect();
+        connections.sort_by(|x, y| y.1.total_cmp(&x.1).then_with(|| x.0.cmp(&y.0)));
+        connections
+    }
+
+    /// Render the module topology as a GraphViz DOT graph
+    ///
+    /// Nodes are filled by each module's last audited status (white until the
+    /// first audit) and every connected pair is drawn once, labeled with its
+    /// strength and any interaction tallies. Asymmetric pairs show both
+    /// directions as `a->b / b->a`. Pipe the output through `dot -Tpng`.
+    pub fn to_dot(&self) -> String {
+        let registry = self.module_registry.read();
+        let synergy_matrix = self.synergy_matrix.read();
+        let edge_metadata = self.edge_metadata.read();
+        let last_statuses = self.last_statuses.read();
+        let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+        
+        let mut output = String::from("graph hypergraph {\n    node [shape=box, style=filled];\n");
+        for name in registry.keys() {
+            let color = match last_statuses.get(name) {
+                Some(ModuleStatus::Healthy) => "palegreen",
+                Some(ModuleStatus::Warning) => "gold",
+                Some(ModuleStatus::Critical) => "salmon",
+                Some(ModuleStatus::Disconnected) => "lightgray",
+                None => "white",
+            };
+            output.push_str(&format!("    \"{}\" [fillcolor={}];\n", escape(name), color));
+        }
+        
+        let mut pairs: Vec<(String, String)> = synergy_matrix.keys()
+            .map(|(a, b)| edge_key(a, b))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        pairs.sort();
+        for (a, b) in pairs {
+            let forward = synergy_matrix.get(&(a.clone(), b.clone())).copied();
+            let backward = synergy_matrix.get(&(b.clone(), a.clone())).copied();
+            let mut label = match (forward, backward) {
+                (Some(x), Some(y)) if x == y => format!("{:.2}", x),
+                (x, y) => {
+                    let show = |s: Option<f64>| s.map(|s| format!("{:.2}", s)).unwrap_or_else(|| "-".to_string());
+                    format!("{} / {}", show(x), show(y))
+                }
+            };
+            if let Some(meta) = edge_metadata.get(&(a.clone(), b.clone())) {
+                for (operation, count) in &meta.operation_counts {
+                    label.push_str(&format!("\\n{} x{}", escape(operation), count));
+                }
+            }
+            let strength = forward.unwrap_or(0.0).max(backward.unwrap_or(0.0));
+            output.push_str(&format!(
+                "    \"{}\" -- \"{}\" [label=\"{}\", penwidth={:.1}];\n",
+                escape(&a), escape(&b), label, 1.0 + 4.0 * strength,
+            ));
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// Degree centrality of every module, most central first
+    ///
+    /// Modules listed in `infrastructure_hubs` are marked, or left out entirely
+    /// when `exclude_infrastructure_hubs` is set, so that hubs created by
+    /// auto-healing don't crowd out genuine functional hubs.
+    pub fn centrality(&self) -> Vec<ModuleCentrality> {
+        self.degree_centrality(!self.settings.exclude_infrastructure_hubs)
+    }
+
+    /// Degree centrality (connections / (n - 1)) of every module, highest first
+    ///
+    /// Infrastructure hubs are marked, and left out unless `include_hubs` is
+    /// set. Ties keep registration order.
+    fn degree_centrality(&self, include_hubs: bool) -> Vec<ModuleCentrality> {
+        let registry = self.module_registry.read();
+        let others = registry.len().saturating_sub(1).max(1) as f64;
+        
+        let mut centrality: Vec<ModuleCentrality> = registry.iter()
+            .map(|(name, metrics)| ModuleCentrality {
+                module_name: name.clone(),
+                degree: metrics.active_connections.len(),
+                score: metrics.active_connections.len() as f64 / others,
+                infrastructure: self.settings.infrastructure_hubs.contains(name),
+            })
+            .filter(|entry| include_hubs || !entry.infrastructure)
+            .collect();
+        centrality.sort_by(|a, b| b.score.total_cmp(&a.score));
+        centrality
+    }
+
+    /// Modules with more connections than `max_connections_before_warn`, most connected first
+    ///
+    /// Infrastructure hubs are expected to be connected to everything and are
+    /// never reported.
+    pub fn over_connected_modules(&self) -> Vec<(String, usize)> {
+        let registry = self.module_registry.read();
+        let mut modules: Vec<(String, usize)> = registry.iter()
+            .filter(|(name, metrics)| self.is_over_connected(name, metrics))
+            .map(|(name, metrics)| (name.clone(), metrics.active_connections.len()))
+            .collect();
+        modules.sort_by_key(|(_, connections)| std::cmp::Reverse(*connections));
+        modules
+    }
+
+    fn is_over_connected(&self, module_name: &str, metrics: &ModuleMetrics) -> bool {
+        let threshold = self.settings.max_connections_before_warn;
+        threshold > 0
+            && metrics.active_connections.len() > threshold
+            && !self.settings.infrastructure_hubs.iter().any(|hub| hub == module_name)
+    }
+
+    /// Normalized degree centrality (connections / (n - 1)) of every module, highest first
+    ///
+    /// Unlike `centrality`, infrastructure hubs are always included. Ties keep
+    /// registration order.
+    pub fn centrality_ranking(&self) -> Vec<(String, f64)> {
+        self.degree_centrality(true)
+            .into_iter()
+            .map(|entry| (entry.module_name, entry.score))
+            .collect()
+    }
+
+    /// Aggregate health, ranking and throughput figures for a dashboard
+    ///
+    /// Status counts and the health score come from the single audit returned
+    /// by `current_audit`, which may be up to one audit tick old. Hubs,
+    /// attention and throughput are each read afterwards under their own locks,
+    /// so under concurrent updates the figures can disagree with each other.
+    pub fn dashboard(&self) -> Result<Dashboard> {
+        let audits = self.current_audit()?;
+        
+        let status_counts = StatusCounts::from_audits(audits.iter());
+        let health_total: f64 = audits.iter().map(|audit| audit.status.health_weight()).sum();
+        let health_score = if audits.is_empty() { 0.0 } else { health_total / audits.len() as f64 };
+        
+        let top_hubs: Vec<(String, usize)> = self.centrality()
+            .into_iter()
+            .take(DASHBOARD_TOP_N)
+            .map(|entry| (entry.module_name, entry.degree))
+            .collect();
+        
+        let mut top_struggling = self.attention_allocation();
+        top_struggling.truncate(DASHBOARD_TOP_N);
+        
+        let windowed = self.windowed_metrics(DASHBOARD_THROUGHPUT_WINDOW);
+        
+        Ok(Dashboard {
+            health_score,
+            status_counts,
+            top_hubs,
+            top_struggling,
+            synergy_coefficient: windowed.synergy_coefficient,
+            throughput: windowed.total_operations as f64 / DASHBOARD_THROUGHPUT_WINDOW.as_secs_f64(),
+        })
+    }
+
+    /// One-paragraph plain-English summary of the latest audit
+    ///
+    /// Meant for readers who do not need the detailed report: it states the
+    /// overall condition, how many modules are healthy, names the worst
+    /// offenders and describes overall coherence.
+    pub fn narrative_summary(&self) -> String {
+        let audits = match self.current_audit() {
+            Ok(audits) => audits,
+            Err(err) => return format!("The system health could not be assessed: {}.", err),
+        };
+        if audits.is_empty() {
+            return "No modules are registered, so there is nothing to assess yet.".to_string();
+        }
+        
+        let mut troubled: Vec<&ModuleAudit> = audits.iter()
+            .filter(|audit| audit.status != ModuleStatus::Healthy)
+            .collect();
+        troubled.sort_by(|a, b| {
+            a.status.severity_rank().cmp(&b.status.severity_rank())
+                .then(a.synergy_score.total_cmp(&b.synergy_score))
+        });
+        
+        let total = audits.len();
+        let healthy = total - troubled.len();
+        let mut sentences = Vec::new();
+        sentences.push(match troubled.first().map(|audit| &audit.status) {
+            None => "The system is healthy.",
+            Some(ModuleStatus::Critical) => "The system needs attention.",
+            Some(_) => "The system is mostly healthy.",
+        }.to_string());
+        sentences.push(format!(
+            "{} of {} module{} {} operating normally.",
+            healthy,
+            total,
+            if total == 1 { "" } else { "s" },
+            if healthy == 1 { "is" } else { "are" },
+        ));
+        
+        if !troubled.is_empty() {
+            let mut clauses: Vec<String> = troubled.iter()
+                .take(NARRATIVE_MAX_MODULES)
+                .map(|audit| {
+                    let condition = match audit.status {
+                        ModuleStatus::Critical => "is in critical condition",
+                        ModuleStatus::Disconnected => "is disconnected from the rest of the system",
+                        _ => "needs attention",
+                    };
+                    format!("the {} module {}", audit.module_name, condition)
+                })
+                .collect();
+            if troubled.len() > NARRATIVE_MAX_MODULES {
+                clauses.push(format!("{} more need attention", troubled.len() - NARRATIVE_MAX_MODULES));
+            }
+            let last = clauses.pop().unwrap_or_default();
+            let mut sentence = if clauses.is_empty() {
+                last
+            } else {
+                format!("{} and {}", clauses.join(", "), last)
+            };
+            sentence[..1].make_ascii_uppercase();
+            sentences.push(format!("{}.", sentence));
+        }
+        
+        let coherence = self.get_performance_metrics().synergy_coefficient;
+        let strength = if coherence >= 0.7 {
+            "strong"
+        } else if coherence >= 0.4 {
+            "moderate"
+        } else {
+            "weak"
+        };
+        sentences.push(format!("Overall coherence is {} at {:.0}%.", strength, coherence * 100.0));
+        
+        sentences.join(" ")
+    }
+
     /// Get current performance metrics
     pub fn get_performance_metrics(&self) -> PerformanceMetrics {
         let perf = self.performance_metrics.read();
@@ -271,10 +3947,13 @@ impl HypergraphCoordinator {
         let synergy_matrix = self.synergy_matrix.read();
         
         // Calculate overall synergy coefficient
-        let synergy_coefficient = if !registry.is_empty() {
-            registry.keys()
-                .map(|module| self.calculate_synergy_score(module, &synergy_matrix))
-                .sum::<f64>() / registry.len() as f64
+        let synergy_coefficient = self.weighted_synergy_coefficient(
+            registry.keys().map(|module| (module.as_str(), self.calculate_synergy_score(module, &synergy_matrix)))
+        );
+        
+        let total_errors: u64 = registry.values().map(|m| m.error_count).sum();
+        let error_rate = if perf.total_operations > 0 {
+            total_errors as f64 / perf.total_operations as f64
         } else {
             0.0
         };
@@ -283,7 +3962,147 @@ impl HypergraphCoordinator {
             total_operations: perf.total_operations,
             average_response_time: perf.average_response_time,
             memory_efficiency: self.calculate_memory_efficiency(),
+            cognitive_efficiency: self.cognitive_efficiency(),
             synergy_coefficient,
+            error_rate,
+        }
+    }
+
+    /// Number of errors `module_name` recorded within the trailing `window`
+    pub fn module_errors_in_window(&self, module_name: &str, window: Duration) -> u64 {
+        let now = self.now();
+        self.metrics_window.read()
+            .iter()
+            .filter(|bucket| now.duration_since(bucket.start) < window)
+            .filter_map(|bucket| bucket.module_errors.get(module_name))
+            .sum()
+    }
+
+    /// Module pairs whose error timelines within `window` move together
+    ///
+    /// Compares per-bucket error counts using Pearson correlation and returns
+    /// pairs at or above `failure_correlation_threshold`, strongest first.
+    /// Modules without any error variation in the window are skipped.
+    pub fn correlated_failures(&self, window: Duration) -> Vec<(String, String, f64)> {
+        let now = self.now();
+        let modules: Vec<String> = self.module_registry.read().keys().cloned().collect();
+        let series: Vec<(String, Vec<f64>)> = {
+            let buckets = self.metrics_window.read();
+            let recent: Vec<&MetricsBucket> = buckets.iter()
+                .filter(|bucket| now.duration_since(bucket.start) < window)
+                .collect();
+            modules.into_iter()
+                .map(|name| {
+                    let counts = recent.iter()
+                        .map(|bucket| bucket.module_errors.get(&name).copied().unwrap_or(0) as f64)
+                        .collect();
+                    (name, counts)
+                })
+                .collect()
+        };
+        
+        let threshold = self.settings.failure_correlation_threshold;
+        let mut pairs = Vec::new();
+        for (i, (module_a, errors_a)) in series.iter().enumerate() {
+            for (module_b, errors_b) in &series[i + 1..] {
+                if let Some(correlation) = pearson_correlation(errors_a, errors_b) {
+                    if correlation >= threshold {
+                        pairs.push((module_a.clone(), module_b.clone(), correlation));
+                    }
+                }
+            }
+        }
+        pairs.sort_by(|a, b| b.2.total_cmp(&a.2));
+        pairs
+    }
+
+    /// Get performance metrics restricted to the trailing `window`
+    ///
+    /// Operations, error rate and mean latency only count activity recorded
+    /// within the window; memory efficiency and synergy are current values.
+    pub fn windowed_metrics(&self, window: Duration) -> PerformanceMetrics {
+        let now = self.now();
+        let (operations, errors, total_latency) = {
+            let buckets = self.metrics_window.read();
+            buckets
+                .iter()
+                .filter(|bucket| now.duration_since(bucket.start) < window)
+                .fold((0u64, 0u64, Duration::ZERO), |(ops, errs, latency), bucket| {
+                    (ops + bucket.operations, errs + bucket.errors, latency + bucket.total_latency)
+                })
+        };
+        
+        let current = self.get_performance_metrics();
+        let (average_response_time, error_rate) = if operations > 0 {
+            (Duration::from_secs_f64(total_latency.as_secs_f64() / operations as f64), errors as f64 / operations as f64)
+        } else {
+            (Duration::ZERO, 0.0)
+        };
+        
+        PerformanceMetrics {
+            total_operations: operations,
+            average_response_time,
+            memory_efficiency: current.memory_efficiency,
+            cognitive_efficiency: current.cognitive_efficiency,
+            synergy_coefficient: current.synergy_coefficient,
+            error_rate,
+        }
+    }
+
+    /// Trailing per-audit series of the performance metrics
+    pub fn performance_series(&self) -> PerformanceSeries {
+        self.performance_series.read().clone()
+    }
+
+    /// Append the current performance metrics to the trailing series
+    fn sample_performance_series(&self, at: Instant) {
+        let metrics = self.get_performance_metrics();
+        self.performance_series.write()
+            .push(at, &metrics, self.settings.performance_series_capacity);
+    }
+
+    /// Direction of the synergy coefficient over the sampled performance series
+    pub fn synergy_coefficient_trend(&self) -> TrendDirection {
+        TrendDirection::from_series(&self.performance_series.read().synergy_coefficient)
+    }
+
+    /// Direction of memory efficiency over the sampled performance series
+    ///
+    /// A steady decline usually means memory grows faster than useful work,
+    /// i.e. a potential leak.
+    pub fn memory_efficiency_trend(&self) -> TrendDirection {
+        TrendDirection::from_series(&self.performance_series.read().memory_efficiency)
+    }
+
+    /// Synergy matrix statistics sampled at each audit, oldest first
+    ///
+    /// Retains the same number of samples as the performance series.
+    pub fn matrix_evolution(&self) -> Vec<MatrixStats> {
+        self.matrix_evolution.read().iter().copied().collect()
+    }
+
+    /// Append the current matrix statistics to the evolution series
+    fn sample_matrix_stats(&self) {
+        let modules = self.module_registry.read().len();
+        // An edge is as strong as its stronger direction; directed-only edges count too
+        let mut strengths: HashMap<(String, String), f64> = HashMap::new();
+        for ((a, b), strength) in self.synergy_matrix.read().iter() {
+            let entry = strengths.entry(edge_key(a, b)).or_insert(*strength);
+            *entry = entry.max(*strength);
+        }
+        let edges = strengths.len();
+        let total_strength: f64 = strengths.values().sum();
+        let possible_edges = modules * modules.saturating_sub(1) / 2;
+        let stats = MatrixStats {
+            edges,
+            mean_strength: if edges > 0 { total_strength / edges as f64 } else { 0.0 },
+            density: if possible_edges > 0 { edges as f64 / possible_edges as f64 } else { 0.0 },
+        };
+        
+        let mut evolution = self.matrix_evolution.write();
+        evolution.push_back(stats);
+        while evolution.len() > self.settings.performance_series_capacity {
+            evolution.pop_front();
         }
     }
 
@@ -301,40 +4120,169 @@ impl HypergraphCoordinator {
         }
     }
 
+    /// Memory efficiency discounted by how loaded the modules are, in `[0, 1]`
+    ///
+    /// `memory_efficiency * (1 - mean load)`, where each module's cognitive
+    /// load is clamped to `[0, 1]` before averaging. A fully loaded system
+    /// scores 0 however lean its memory footprint; with no modules it is 1.
+    pub fn cognitive_efficiency(&self) -> f64 {
+        let mean_load = {
+            let registry = self.module_registry.read();
+            if registry.is_empty() {
+                return 1.0;
+            }
+            registry.values()
+                .map(|m| if m.cognitive_load.is_finite() { m.cognitive_load.clamp(0.0, 1.0) } else { 1.0 })
+                .sum::<f64>() / registry.len() as f64
+        };
+        self.calculate_memory_efficiency() * (1.0 - mean_load)
+    }
+
+    /// Dispatch a serialized command to the matching coordinator method
+    pub fn handle_command(&self, cmd: CoordinatorCommand) -> CommandResponse {
+        let result = match cmd {
+            CoordinatorCommand::Register { module } => {
+                self.register_module(&module).map(|_| CommandResponse::Ok)
+            }
+            CoordinatorCommand::Connect { module_a, module_b, strength } => {
+                self.establish_connection(&module_a, &module_b, strength).map(|_| CommandResponse::Ok)
+            }
+            CoordinatorCommand::RecordActivity { module, operation_type, duration_ms } => {
+                self.record_activity(&module, &operation_type, Duration::from_millis(duration_ms))
+                    .map(|_| CommandResponse::Ok)
+            }
+            CoordinatorCommand::RecordError { module, error } => {
+                self.record_error(&module, &error).map(|_| CommandResponse::Ok)
+            }
+            CoordinatorCommand::Audit => {
+                self.audit_core_modules().map(|audits| CommandResponse::Audit {
+                    modules: audits.iter().map(AuditSummary::from).collect(),
+                })
+            }
+            CoordinatorCommand::Report => {
+                // Command consumers are not terminals, so never colorize
+                self.render_health_report(false).map(|report| CommandResponse::Report { report })
+            }
+        };
+        
+        result.unwrap_or_else(|err| CommandResponse::Error { message: err.to_string() })
+    }
+
     /// Generate a comprehensive system health report
+    ///
+    /// Status lines are colorized when stdout is a terminal and `NO_COLOR` is
+    /// unset, or whenever `force_color` is enabled. This is meant for printing;
+    /// reports sent anywhere else should be rendered without color. Audit
+    /// failures are listed in an "Audit Errors" section instead of failing the
+    /// whole report.
     pub fn generate_health_report(&self) -> Result<String> {
-        let audits = self.audit_core_modules()?;
+        self.render_health_report(report_color(self.settings.force_color, *crate::utils::NO_COLOR))
+    }
+
+    /// Health report as JSON, with the same content as `generate_health_report`
+    pub fn generate_health_report_json(&self) -> Result<serde_json::Value> {
+        Ok(stabilize_json(serde_json::to_value(self.health_report())?))
+    }
+
+    /// Run an audit and assemble the structured health report
+    ///
+    /// An audit failure yields a report with no modules and the failure
+    /// listed under `audit_errors`.
+    pub fn health_report(&self) -> HealthReport {
+        let (audits, audit_errors) = match self.audit_core_modules() {
+            Ok(audits) => (audits, self.last_audit_errors()),
+            Err(err) => {
+                log::warn!("Rendering partial health report: {}", err);
+                (Vec::new(), vec![format!("Audit failed: {}", err)])
+            }
+        };
+        self.health_report_from(&audits, audit_errors)
+    }
+
+    /// Assemble a health report from audits that were already run
+    pub(crate) fn health_report_from(&self, audits: &[ModuleAudit], audit_errors: Vec<String>) -> HealthReport {
+        let mut modules: Vec<AuditSummary> = audits.iter().map(AuditSummary::from).collect();
+        match self.settings.report_order {
+            ReportOrder::Registration => {}
+            ReportOrder::Alphabetical => {
+                modules.sort_by(|a, b| a.module_name.cmp(&b.module_name));
+            }
+            ReportOrder::Severity => {
+                modules.sort_by(|a, b| {
+                    a.status.severity_rank().cmp(&b.status.severity_rank())
+                        .then_with(|| a.module_name.cmp(&b.module_name))
+                });
+            }
+        }
         let metrics = self.get_performance_metrics();
+        let mut operation_latencies: IndexMap<String, LatencyStats> = self.operation_latencies.read()
+            .iter()
+            .map(|(operation, tracker)| (operation.clone(), tracker.stats()))
+            .collect();
+        operation_latencies.sort_keys();
+        
+        HealthReport {
+            features: self.enabled_features(),
+            metrics: ReportMetrics {
+                total_operations: metrics.total_operations,
+                average_response_time_ms: metrics.average_response_time.as_secs_f64() * 1000.0,
+                memory_efficiency: metrics.memory_efficiency,
+                memory_efficiency_trend: self.memory_efficiency_trend(),
+                cognitive_efficiency: metrics.cognitive_efficiency,
+                synergy_coefficient: metrics.synergy_coefficient,
+                error_rate: metrics.error_rate,
+            },
+            status_counts: StatusCounts::from_audits(audits),
+            audit_errors,
+            operation_latencies,
+            modules,
+        }
+    }
+
+    pub(crate) fn render_health_report(&self, color: bool) -> Result<String> {
+        let health = self.health_report();
+        let metrics = &health.metrics;
         
         let mut report = String::new();
         
         report.push_str("╔══════════════════════════════════════════════════════════════╗\n");
         report.push_str("║                  Hypergraph Synergy Report                  ║\n");
-        report.push_str("╚══════════════════════════════════════════════════════════════╝\n\n");
+        report.push_str("╚══════════════════════════════════════════════════════════════╝\n");
+        report.push_str(&format!("⚙️  Features: {}\n\n", health.features));
         
         // Overall metrics
         report.push_str(&format!("📊 Performance Metrics:\n"));
         report.push_str(&format!("   Total Operations: {}\n", metrics.total_operations));
         report.push_str(&format!("   Average Response Time: {:.2}ms\n", 
-                                metrics.average_response_time.as_millis()));
+                                metrics.average_response_time_ms));
         report.push_str(&format!("   Memory Efficiency: {:.2}%\n", metrics.memory_efficiency * 100.0));
-        report.push_str(&format!("   Synergy Coefficient: {:.2}%\n\n", metrics.synergy_coefficient * 100.0));
+        report.push_str(&format!("   Cognitive Efficiency: {:.2}%\n", metrics.cognitive_efficiency * 100.0));
+        report.push_str(&format!("   Synergy Coefficient: {:.2}%\n", metrics.synergy_coefficient * 100.0));
+        if metrics.memory_efficiency_trend == TrendDirection::Degrading {
+            report.push_str("   ⚠️  Memory efficiency is steadily declining (potential leak)\n");
+        }
+        report.push('\n');
         
         // Module status summary
-        let healthy_count = audits.iter().filter(|a| matches!(a.status, ModuleStatus::Healthy)).count();
-        let warning_count = audits.iter().filter(|a| matches!(a.status, ModuleStatus::Warning)).count();
-        let critical_count = audits.iter().filter(|a| matches!(a.status, ModuleStatus::Critical)).count();
-        let disconnected_count = audits.iter().filter(|a| matches!(a.status, ModuleStatus::Disconnected)).count();
-        
+        let counts = &health.status_counts;
         report.push_str(&format!("🔍 Module Status Summary:\n"));
-        report.push_str(&format!("   ✅ Healthy: {}\n", healthy_count));
-        report.push_str(&format!("   ⚠️  Warning: {}\n", warning_count));
-        report.push_str(&format!("   🚨 Critical: {}\n", critical_count));
-        report.push_str(&format!("   🔌 Disconnected: {}\n\n", disconnected_count));
+        report.push_str(&format!("   ✅ Healthy: {}\n", counts.healthy));
+        report.push_str(&format!("   ⚠️  Warning: {}\n", counts.warning));
+        report.push_str(&format!("   🚨 Critical: {}\n", counts.critical));
+        report.push_str(&format!("   🔌 Disconnected: {}\n\n", counts.disconnected));
+        
+        if !health.audit_errors.is_empty() {
+            report.push_str("❗ Audit Errors:\n");
+            for error in &health.audit_errors {
+                push_wrapped(&mut report, "   • ", error, self.settings.report_max_width);
+            }
+            report.push('\n');
+        }
         
         // Detailed module reports
         report.push_str("📋 Detailed Module Analysis:\n\n");
-        for audit in &audits {
+        let max_width = self.settings.report_max_width;
+        for audit in &health.modules {
             let status_emoji = match audit.status {
                 ModuleStatus::Healthy => "✅",
                 ModuleStatus::Warning => "⚠️",
@@ -342,21 +4290,39 @@ impl HypergraphCoordinator {
                 ModuleStatus::Disconnected => "🔌",
             };
             
-            report.push_str(&format!("{} {} (Synergy: {:.2}%, Connections: {})\n", 
-                                   status_emoji, audit.module_name, 
-                                   audit.synergy_score * 100.0, audit.hypergraph_connections));
+            let details = format!(" (Synergy: {:.2}%, Connections: {})", 
+                                  audit.synergy_score * 100.0, audit.hypergraph_connections);
+            let module_name = match max_width {
+                0 => audit.module_name.clone(),
+                width => {
+                    let available = width.saturating_sub(status_emoji.chars().count() + 1 + details.chars().count());
+                    truncate_with_ellipsis(&audit.module_name, available.max(MIN_TRUNCATED_NAME))
+                }
+            };
+            let status_line = format!("{} {}{}", status_emoji, module_name, details);
+            if color {
+                let style = match audit.status {
+                    ModuleStatus::Healthy => Color::Green.normal(),
+                    ModuleStatus::Warning => Color::Yellow.normal(),
+                    ModuleStatus::Critical => Color::Red.bold(),
+                    ModuleStatus::Disconnected => Style::new().dimmed(),
+                };
+                report.push_str(&format!("{}\n", style.paint(status_line)));
+            } else {
+                report.push_str(&format!("{}\n", status_line));
+            }
             
             if !audit.issues.is_empty() {
                 report.push_str("   Issues:\n");
                 for issue in &audit.issues {
-                    report.push_str(&format!("   • {}\n", issue));
+                    push_wrapped(&mut report, "   • ", issue, max_width);
                 }
             }
             
             if !audit.recommendations.is_empty() {
                 report.push_str("   Recommendations:\n");
                 for rec in &audit.recommendations {
-                    report.push_str(&format!("   → {}\n", rec));
+                    push_wrapped(&mut report, "   → ", rec, max_width);
                 }
             }
             
@@ -367,23 +4333,170 @@ impl HypergraphCoordinator {
     }
 }
 
+/// Inspection-only handle to a coordinator
+///
+/// Exposes queries that never change coordinator state. Recording,
+/// connection and configuration methods are deliberately absent, and so are
+/// audits and reports, since running an audit expires edges and appends to
+/// history.
+#[derive(Clone, Copy)]
+pub struct ReadOnlyCoordinator<'a> {
+    inner: &'a HypergraphCoordinator,
+}
+
+impl ReadOnlyCoordinator<'_> {
+    /// Names of registered modules, in registration order
+    pub fn modules(&self) -> Vec<String> {
+        self.inner.module_registry.read().keys().cloned().collect()
+    }
+
+    /// Status recorded for `module_name` by the most recent audit
+    pub fn last_status(&self, module_name: &str) -> Option<ModuleStatus> {
+        self.inner.last_statuses.read().get(module_name).cloned()
+    }
+
+    pub fn snapshot(&self) -> HypergraphSnapshot {
+        self.inner.snapshot()
+    }
+
+    pub fn get_performance_metrics(&self) -> PerformanceMetrics {
+        self.inner.get_performance_metrics()
+    }
+
+    pub fn windowed_metrics(&self, window: Duration) -> PerformanceMetrics {
+        self.inner.windowed_metrics(window)
+    }
+
+    pub fn performance_series(&self) -> PerformanceSeries {
+        self.inner.performance_series()
+    }
+
+    pub fn matrix_evolution(&self) -> Vec<MatrixStats> {
+        self.inner.matrix_evolution()
+    }
+
+    pub fn operation_time_breakdown(&self) -> HashMap<String, Duration> {
+        self.inner.operation_time_breakdown()
+    }
+
+    pub fn retrieval_quality(&self) -> Option<RetrievalQuality> {
+        self.inner.retrieval_quality()
+    }
+
+    pub fn centrality(&self) -> Vec<ModuleCentrality> {
+        self.inner.centrality()
+    }
+
+    pub fn centrality_ranking(&self) -> Vec<(String, f64)> {
+        self.inner.centrality_ranking()
+    }
+
+    pub fn audit_history_between(&self, module: Option<&str>, since: Instant, until: Instant) -> Vec<ModuleAudit> {
+        self.inner.audit_history_between(module, since, until)
+    }
+
+    pub fn top_connections(&self, n: usize) -> Vec<(String, String, f64)> {
+        self.inner.top_connections(n)
+    }
+
+    pub fn connections_of(&self, module_name: &str) -> Vec<(String, f64)> {
+        self.inner.connections_of(module_name)
+    }
+
+    // Borrow like every other method on the view, even though it is `Copy`
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_dot(&self) -> String {
+        self.inner.to_dot()
+    }
+
+    pub fn connected_components(&self) -> Vec<HashSet<String>> {
+        self.inner.connected_components()
+    }
+
+    pub fn attention_allocation(&self) -> Vec<(String, f64)> {
+        self.inner.attention_allocation()
+    }
+
+    pub fn strongest_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        self.inner.strongest_path(from, to)
+    }
+
+    pub fn strongest_path_with_strength(&self, module_a: &str, module_b: &str, indirect_only: bool) -> Option<(Vec<String>, f64)> {
+        self.inner.strongest_path_with_strength(module_a, module_b, indirect_only)
+    }
+
+    pub fn effective_synergy(&self, module_a: &str, module_b: &str) -> f64 {
+        self.inner.effective_synergy(module_a, module_b)
+    }
+
+    pub fn topology_coverage(&self) -> Result<Coverage> {
+        self.inner.topology_coverage()
+    }
+
+    pub fn recent_events(&self, n: usize) -> Vec<CoordinatorEvent> {
+        self.inner.recent_events(n)
+    }
+
+    pub fn enabled_features(&self) -> FeatureFlags {
+        self.inner.enabled_features()
+    }
+}
+
+impl HypergraphCoordinator {
+    /// Borrow an inspection-only view that cannot mutate this coordinator
+    pub fn read_only_view(&self) -> ReadOnlyCoordinator<'_> {
+        ReadOnlyCoordinator { inner: self }
+    }
+}
+
 /// Global instance for hypergraph coordination
-static HYPERGRAPH_COORDINATOR: once_cell::sync::OnceCell<Arc<HypergraphCoordinator>> = once_cell::sync::OnceCell::new();
+///
+/// A lock rather than a `OnceCell` so tests can reset it between runs.
+static HYPERGRAPH_COORDINATOR: RwLock<Option<Arc<HypergraphCoordinator>>> = parking_lot::const_rwlock(None);
 
 /// Initialize the global hypergraph coordinator
 pub fn init_hypergraph_coordinator(config: GlobalConfig) -> Result<()> {
-    let coordinator = Arc::new(HypergraphCoordinator::new(config));
-    
-    HYPERGRAPH_COORDINATOR.set(coordinator)
-        .map_err(|_| anyhow!("Hypergraph coordinator already initialized"))?;
+    let mut global = HYPERGRAPH_COORDINATOR.write();
+    if global.is_some() {
+        return Err(anyhow!("Hypergraph coordinator already initialized"));
+    }
+    *global = Some(Arc::new(HypergraphCoordinator::new(config)));
     
     Ok(())
 }
 
+/// Drop the global hypergraph coordinator so it can be initialized again
+///
+/// Test-only: production code initializes the coordinator exactly once, and
+/// handles already obtained from `get_hypergraph_coordinator` keep pointing
+/// at the old instance.
+#[cfg(test)]
+pub(crate) fn reset_hypergraph_coordinator() {
+    *HYPERGRAPH_COORDINATOR.write() = None;
+}
+
+/// Initialize the global hypergraph coordinator, or return the existing one
+///
+/// Unlike `init_hypergraph_coordinator` this never errors, so concurrent boot
+/// paths can all call it and share a single instance. `config` is only used
+/// by the call that actually performs initialization.
+pub fn init_or_get(config: GlobalConfig) -> Arc<HypergraphCoordinator> {
+    init_or_get_in(&HYPERGRAPH_COORDINATOR, config)
+}
+
+pub(crate) fn init_or_get_in(
+    cell: &RwLock<Option<Arc<HypergraphCoordinator>>>,
+    config: GlobalConfig,
+) -> Arc<HypergraphCoordinator> {
+    cell.write()
+        .get_or_insert_with(|| Arc::new(HypergraphCoordinator::new(config)))
+        .clone()
+}
+
 /// Get the global hypergraph coordinator instance
 pub fn get_hypergraph_coordinator() -> Result<Arc<HypergraphCoordinator>> {
-    HYPERGRAPH_COORDINATOR.get()
-        .cloned()
+    HYPERGRAPH_COORDINATOR.read()
+        .clone()
         .ok_or_else(|| anyhow!("Hypergraph coordinator not initialized"))
 }
 
@@ -392,6 +4505,11 @@ pub fn register_module(module_name: &str) -> Result<()> {
     get_hypergraph_coordinator()?.register_module(module_name)
 }
 
+/// Convenience function to register a batch of modules
+pub fn register_modules(names: &[&str]) -> Result<()> {
+    get_hypergraph_coordinator()?.register_modules(names)
+}
+
 /// Convenience function to establish module connections
 pub fn establish_connection(module_a: &str, module_b: &str, strength: f64) -> Result<()> {
     get_hypergraph_coordinator()?.establish_connection(module_a, module_b, strength)
@@ -402,6 +4520,11 @@ pub fn record_activity(module_name: &str, operation_type: &str, duration: Durati
     get_hypergraph_coordinator()?.record_activity(module_name, operation_type, duration)
 }
 
+/// Convenience function to record a RAG retrieval with its quality signal
+pub fn record_rag_retrieval(top_score: f64, hits: usize, duration: Duration) -> Result<()> {
+    get_hypergraph_coordinator()?.record_rag_retrieval(top_score, hits, duration)
+}
+
 /// Convenience function to record errors
 pub fn record_error(module_name: &str, error: &str) -> Result<()> {
     get_hypergraph_coordinator()?.record_error(module_name, error)
@@ -412,6 +4535,11 @@ pub fn audit_core_modules() -> Result<Vec<ModuleAudit>> {
     get_hypergraph_coordinator()?.audit_core_modules()
 }
 
+/// Convenience function to decay connection strengths
+pub fn decay_connections() -> Result<usize> {
+    Ok(get_hypergraph_coordinator()?.decay_connections())
+}
+
 /// Convenience function to generate health report
 pub fn generate_health_report() -> Result<String> {
     get_hypergraph_coordinator()?.generate_health_report()
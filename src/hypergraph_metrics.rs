@@ -0,0 +1,110 @@
+//! Metrics exporters for the hypergraph coordinator
+//!
+//! `render_prometheus` backs the server's `/metrics` endpoint. The OpenMetrics
+//! latency histograms are enabled with the `metrics` feature.
+
+use crate::hypergraph::HypergraphCoordinator;
+#[cfg(feature = "metrics")]
+use crate::hypergraph::LATENCY_BUCKETS_SECONDS;
+
+use std::fmt::Write;
+
+/// Render coordinator and per-module metrics in the Prometheus text exposition format
+pub fn render_prometheus(coordinator: &HypergraphCoordinator) -> String {
+    let mut output = String::new();
+    let metrics = coordinator.get_performance_metrics();
+    let globals = [
+        ("caichat_total_operations", "counter", "Operations recorded across all modules.",
+         metrics.total_operations as f64),
+        ("caichat_average_response_time_ms", "gauge", "Moving average of operation latency in milliseconds.",
+         metrics.average_response_time.as_secs_f64() * 1000.0),
+        ("caichat_memory_efficiency", "gauge", "Operations per unit of sampled memory, in [0, 1].",
+         metrics.memory_efficiency),
+        ("caichat_synergy_coefficient", "gauge", "Weighted mean synergy score of all modules.",
+         metrics.synergy_coefficient),
+    ];
+    for (name, kind, help, value) in globals {
+        let _ = writeln!(output, "# HELP {name} {help}");
+        let _ = writeln!(output, "# TYPE {name} {kind}");
+        let _ = writeln!(output, "{name} {value}");
+    }
+    
+    let registry = coordinator.module_registry.read();
+    let per_module = [
+        ("caichat_module_cognitive_load", "Current cognitive load of the module.",
+         registry.values().map(|m| (&m.name, m.cognitive_load)).collect::<Vec<_>>()),
+        ("caichat_module_error_count", "Errors recorded by the module.",
+         registry.values().map(|m| (&m.name, m.error_count as f64)).collect()),
+        ("caichat_module_hypergraph_connections", "Active hypergraph connections of the module.",
+         registry.values().map(|m| (&m.name, m.active_connections.len() as f64)).collect()),
+    ];
+    for (name, help, values) in per_module {
+        if values.is_empty() {
+            continue;
+        }
+        let _ = writeln!(output, "# HELP {name} {help}");
+        let _ = writeln!(output, "# TYPE {name} gauge");
+        for (module, value) in values {
+            let _ = writeln!(output, "{name}{{module=\"{}\"}} {value}", escape_label_value(module));
+        }
+    }
+    drop(registry);
+    
+    let module_gauges = coordinator.module_gauges();
+    if !module_gauges.is_empty() {
+        output.push_str("# HELP caichat_module_gauge Custom gauges set by modules.\n");
+        output.push_str("# TYPE caichat_module_gauge gauge\n");
+        for (module, gauges) in module_gauges {
+            for (key, value) in gauges {
+                let _ = writeln!(
+                    output,
+                    "caichat_module_gauge{{module=\"{}\",key=\"{}\"}} {value}",
+                    escape_label_value(&module),
+                    escape_label_value(&key),
+                );
+            }
+        }
+    }
+    output
+}
+
+/// Render per-module latency histograms in the OpenMetrics text format
+///
+/// Buckets that saw traced activity carry the most recent trace ID as an
+/// exemplar, so a latency spike on a dashboard links straight to a trace.
+#[cfg(feature = "metrics")]
+pub fn render_openmetrics(coordinator: &HypergraphCoordinator) -> String {
+    let mut output = String::new();
+    let name = "caichat_module_latency_seconds";
+    let _ = writeln!(output, "# TYPE {name} histogram");
+    let _ = writeln!(output, "# HELP {name} Latency of recorded module activity.");
+
+    for (module, histogram) in coordinator.latency_histograms() {
+        let module = escape_label_value(&module);
+        let mut cumulative = 0;
+        for (index, count) in histogram.counts.iter().enumerate() {
+            cumulative += count;
+            let bound = LATENCY_BUCKETS_SECONDS
+                .get(index)
+                .map(|bound| bound.to_string())
+                .unwrap_or_else(|| "+Inf".to_string());
+            let _ = write!(output, "{name}_bucket{{module=\"{module}\",le=\"{bound}\"}} {cumulative}");
+            if let Some((trace_id, value)) = &histogram.exemplars[index] {
+                let _ = write!(output, " # {{trace_id=\"{}\"}} {}", escape_label_value(trace_id), value);
+            }
+            output.push('\n');
+        }
+        let _ = writeln!(output, "{name}_count{{module=\"{module}\"}} {cumulative}");
+        let _ = writeln!(output, "{name}_sum{{module=\"{module}\"}} {}", histogram.sum);
+    }
+
+    output.push_str("# EOF\n");
+    output
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
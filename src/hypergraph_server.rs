@@ -0,0 +1,129 @@
+//! Minimal HTTP server exposing the hypergraph coordinator
+//!
+//! Enabled with the `server` feature. Routes:
+//! - `GET /health` overall status summary
+//! - `GET /report` human-readable health report
+//! - `GET /report.json` machine-readable health report
+//! - `GET /modules/:name` audit for a single module
+//! - `GET /metrics` Prometheus text exposition
+
+use crate::hypergraph::{stabilize_json, AuditSummary, HypergraphCoordinator, ModuleStatus};
+use crate::hypergraph_metrics::render_prometheus;
+
+use anyhow::{anyhow, Result};
+use bytes::Bytes;
+use http::{Method, Response, StatusCode};
+use http_body_util::Full;
+use hyper::{body::Incoming, service::service_fn};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use serde_json::json;
+use std::{convert::Infallible, sync::Arc};
+use tokio::net::TcpListener;
+
+type HypergraphResponse = Response<Full<Bytes>>;
+
+/// Bind `addr` and serve the coordinator until the task is dropped
+pub async fn serve(coordinator: Arc<HypergraphCoordinator>, addr: &str) -> Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("Hypergraph API: http://{}", listener.local_addr()?);
+    serve_listener(coordinator, listener).await
+}
+
+/// Serve the coordinator on an already-bound listener
+pub async fn serve_listener(coordinator: Arc<HypergraphCoordinator>, listener: TcpListener) -> Result<()> {
+    loop {
+        let Ok((cnx, _)) = listener.accept().await else {
+            continue;
+        };
+
+        let stream = TokioIo::new(cnx);
+        let coordinator = coordinator.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |req: hyper::Request<Incoming>| {
+                let coordinator = coordinator.clone();
+                async move { Ok::<_, Infallible>(handle(&coordinator, &req)) }
+            });
+            let _ = hyper_util::server::conn::auto::Builder::new(TokioExecutor::new())
+                .serve_connection(stream, service)
+                .await;
+        });
+    }
+}
+
+fn handle(coordinator: &HypergraphCoordinator, req: &hyper::Request<Incoming>) -> HypergraphResponse {
+    let method = req.method();
+    let path = req.uri().path();
+
+    let res = if method != Method::GET {
+        Err((StatusCode::METHOD_NOT_ALLOWED, anyhow!("Method Not Allowed")))
+    } else if path == "/health" {
+        health(coordinator)
+    } else if path == "/report" {
+        coordinator.render_health_report(false)
+            .map(|report| text_response(report, "text/plain; charset=utf-8"))
+            .map_err(internal_error)
+    } else if path == "/report.json" {
+        report_json(coordinator)
+    } else if let Some(name) = path.strip_prefix("/modules/") {
+        module(coordinator, name)
+    } else if path == "/metrics" {
+        Ok(text_response(render_prometheus(coordinator), "text/plain; version=0.0.4"))
+    } else {
+        Err((StatusCode::NOT_FOUND, anyhow!("Not Found")))
+    };
+
+    match res {
+        Ok(res) => {
+            debug!("{method} {path} 200");
+            res
+        }
+        Err((status, err)) => {
+            debug!("{method} {path} {} {err}", status.as_u16());
+            let mut res = json_response(json!({ "error": err.to_string() }));
+            *res.status_mut() = status;
+            res
+        }
+    }
+}
+
+fn health(coordinator: &HypergraphCoordinator) -> Result<HypergraphResponse, (StatusCode, anyhow::Error)> {
+    let audits = coordinator.current_audit().map_err(internal_error)?;
+    let status = if audits.iter().any(|a| matches!(a.status, ModuleStatus::Critical)) {
+        "critical"
+    } else if audits.iter().any(|a| !matches!(a.status, ModuleStatus::Healthy)) {
+        "degraded"
+    } else {
+        "healthy"
+    };
+    Ok(json_response(json!({ "status": status, "modules": audits.len() })))
+}
+
+fn report_json(coordinator: &HypergraphCoordinator) -> Result<HypergraphResponse, (StatusCode, anyhow::Error)> {
+    let audits = coordinator.current_audit().map_err(internal_error)?;
+    let report = coordinator.health_report_from(&audits, coordinator.last_audit_errors());
+    Ok(json_response(stabilize_json(json!(report))))
+}
+
+fn module(coordinator: &HypergraphCoordinator, name: &str) -> Result<HypergraphResponse, (StatusCode, anyhow::Error)> {
+    let audits = coordinator.current_audit().map_err(internal_error)?;
+    let audit = audits.iter()
+        .find(|a| a.module_name == name)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, anyhow!("Module '{}' not found", name)))?;
+    Ok(json_response(json!(AuditSummary::from(audit))))
+}
+
+fn json_response(data: serde_json::Value) -> HypergraphResponse {
+    text_response(data.to_string(), "application/json; charset=utf-8")
+}
+
+fn text_response(body: String, content_type: &str) -> HypergraphResponse {
+    let mut res = Response::new(Full::new(Bytes::from(body)));
+    if let Ok(value) = content_type.parse() {
+        res.headers_mut().insert(http::header::CONTENT_TYPE, value);
+    }
+    res
+}
+
+fn internal_error(err: anyhow::Error) -> (StatusCode, anyhow::Error) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err)
+}
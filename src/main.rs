@@ -4,6 +4,10 @@ mod config;
 mod config_reinforcement;
 mod function;
 mod hypergraph;
+#[cfg(any(feature = "metrics", feature = "server"))]
+mod hypergraph_metrics;
+#[cfg(feature = "server")]
+mod hypergraph_server;
 mod rag;
 mod render;
 mod repl;
@@ -58,7 +62,8 @@ async fn main() -> Result<()> {
         || cli.list_rags
         || cli.list_macros
         || cli.list_sessions
-        || cli.audit_hypergraph;
+        || cli.audit_hypergraph
+        || cli.hypergraph_report;
     setup_logger(working_mode.is_serve())?;
     let config = Arc::new(RwLock::new(Config::init(working_mode, info_flag).await?));
     
@@ -69,12 +74,7 @@ async fn main() -> Result<()> {
     crate::config_reinforcement::init_config_reinforcement(None)?;
     
     // Register core modules for hypergraph tracking
-    crate::hypergraph::register_module("client")?;
-    crate::hypergraph::register_module("config")?;
-    crate::hypergraph::register_module("rag")?;
-    crate::hypergraph::register_module("repl")?;
-    crate::hypergraph::register_module("function")?;
-    crate::hypergraph::register_module("serve")?;
+    crate::hypergraph::register_modules(&["client", "config", "rag", "repl", "function", "serve"])?;
     
     // Establish module synergy connections
     crate::hypergraph::establish_connection("client", "config", 0.9)?;
@@ -92,9 +92,46 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Print the hypergraph health report, re-rendering every `watch` seconds if given
+async fn hypergraph_report(json: bool, watch: Option<u64>, abort_signal: AbortSignal) -> Result<()> {
+    let coordinator = crate::hypergraph::get_hypergraph_coordinator()?;
+    let render = || -> Result<String> {
+        if json {
+            Ok(serde_json::to_string_pretty(&coordinator.generate_health_report_json()?)?)
+        } else {
+            coordinator.generate_health_report()
+        }
+    };
+    
+    let Some(seconds) = watch else {
+        println!("{}", render()?);
+        return Ok(());
+    };
+    if seconds == 0 {
+        bail!("--watch needs an interval of at least one second");
+    }
+    loop {
+        let report = render()?;
+        if *IS_STDOUT_TERMINAL {
+            // Clear the previous rendering so the report updates in place
+            print!("\x1b[2J\x1b[H");
+        }
+        println!("{}", report);
+        tokio::select! {
+            _ = tokio::time::sleep(std::time::Duration::from_secs(seconds)) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            _ = wait_abort_signal(&abort_signal) => return Ok(()),
+        }
+    }
+}
+
 async fn run(config: GlobalConfig, cli: Cli, text: Option<String>) -> Result<()> {
     let abort_signal = create_abort_signal();
 
+    if cli.hypergraph_report {
+        return hypergraph_report(cli.json, cli.watch, abort_signal).await;
+    }
+
     if cli.sync_models {
         let url = config.read().sync_models_url();
         return Config::sync_models(&url, abort_signal.clone()).await;
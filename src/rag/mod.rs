@@ -311,19 +311,27 @@ impl Rag {
         
         let result = match ret {
             Ok(results) => {
-                let (ids, documents): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+                // Record RAG retrieval quality for hypergraph tracking
+                let top_score = results.iter().map(|(_, _, score)| *score).fold(0.0, f64::max);
+                if let Err(err) = hypergraph::record_rag_retrieval(top_score, results.len(), start_time.elapsed()) {
+                    log::warn!("Failed to record RAG retrieval: {}", err);
+                }
+                let (ids, documents): (Vec<_>, Vec<_>) = results
+                    .into_iter()
+                    .map(|(id, document, _)| (id, document))
+                    .unzip();
                 let embeddings = documents.join("\n\n");
                 Ok((embeddings, ids))
             }
-            Err(err) => Err(err)
+            Err(err) => {
+                // Record RAG activity for hypergraph tracking
+                if let Err(err) = hypergraph::record_activity("rag", "rag_query", start_time.elapsed()) {
+                    log::warn!("Failed to record RAG activity: {}", err);
+                }
+                Err(err)
+            }
         };
         
-        // Record RAG activity for hypergraph tracking
-        let operation_duration = start_time.elapsed();
-        if let Err(err) = hypergraph::record_activity("rag", "rag_query", operation_duration) {
-            log::warn!("Failed to record RAG activity: {}", err);
-        }
-        
         // Record errors if any
         if let Err(ref error) = result {
             if let Err(err) = hypergraph::record_error("rag", &error.to_string()) {
@@ -530,12 +538,15 @@ impl Rag {
         Ok(())
     }
 
+    /// Each result carries its relevance score: the reranker's score when a
+    /// reranker is used, otherwise the vector similarity (0.0 for documents
+    /// only matched by keyword)
     async fn hybird_search(
         &self,
         query: &str,
         top_k: usize,
         rerank_model: Option<&str>,
-    ) -> Result<Vec<(DocumentId, String)>> {
+    ) -> Result<Vec<(DocumentId, String, f64)>> {
         let (vector_search_results, keyword_search_results) = tokio::join!(
             self.vector_search(query, top_k, 0.0),
             self.keyword_search(query, top_k, 0.0),
@@ -543,6 +554,11 @@ impl Rag {
 
         let vector_search_results = vector_search_results?;
         debug!("vector_search_results: {vector_search_results:?}",);
+        let mut vector_scores: HashMap<DocumentId, f64> = HashMap::new();
+        for (id, score) in vector_search_results.iter() {
+            let best = vector_scores.entry(*id).or_default();
+            *best = best.max(*score as f64);
+        }
         let vector_search_ids: Vec<DocumentId> =
             vector_search_results.into_iter().map(|(v, _)| v).collect();
 
@@ -573,7 +589,10 @@ impl Rag {
                 let ids: Vec<_> = list
                     .into_iter()
                     .take(top_k)
-                    .filter_map(|item| documents_ids.get(item.index).cloned())
+                    .filter_map(|item| {
+                        let id = documents_ids.get(item.index).cloned()?;
+                        Some((id, item.relevance_score))
+                    })
                     .collect();
                 debug!("rerank_ids: {ids:?}");
                 ids
@@ -585,14 +604,16 @@ impl Rag {
                     top_k,
                 );
                 debug!("rrf_ids: {ids:?}");
-                ids
+                ids.into_iter()
+                    .map(|id| (id, vector_scores.get(&id).copied().unwrap_or_default()))
+                    .collect()
             }
         };
         let output = ids
             .into_iter()
-            .filter_map(|id| {
+            .filter_map(|(id, score)| {
                 let document = self.data.get(id)?;
-                Some((id, document.page_content.clone()))
+                Some((id, document.page_content.clone(), score))
             })
             .collect();
         Ok(output)
@@ -6,6 +6,7 @@
 #[cfg(test)]
 mod tests {
     use crate::config::GlobalConfig;
+    use crate::config_reinforcement::{ConfigReinforcement, HealingAction, HealingOutcome, HypergraphConfig};
     use crate::hypergraph::*;
     use std::sync::Arc;
     use std::time::Duration;
@@ -63,8 +64,8 @@ mod tests {
         assert!(registry.get("module_b").unwrap().active_connections.contains("module_a"));
         
         // Check synergy matrix
-        assert_eq!(synergy_matrix.get(&("module_a".to_string(), "module_b".to_string())), Some(&0.8));
-        assert_eq!(synergy_matrix.get(&("module_b".to_string(), "module_a".to_string())), Some(&0.8));
+        assert!(approx_eq(synergy_matrix[&("module_a".to_string(), "module_b".to_string())], 0.8));
+        assert!(approx_eq(synergy_matrix[&("module_b".to_string(), "module_a".to_string())], 0.8));
     }
 
     #[tokio::test]
@@ -114,8 +115,9 @@ mod tests {
         coordinator.register_module("warning_module").unwrap();
         coordinator.register_module("disconnected_module").unwrap();
         
-        // Create some connections
-        coordinator.establish_connection("healthy_module", "warning_module", 0.9).unwrap();
+        // Create some connections; the warning module only weakly reciprocates
+        coordinator.establish_directed_connection("healthy_module", "warning_module", 0.9).unwrap();
+        coordinator.establish_directed_connection("warning_module", "healthy_module", 0.1).unwrap();
         
         // Record activities
         coordinator.record_activity("healthy_module", "llm_completion", Duration::from_millis(50)).unwrap();
@@ -187,7 +189,9 @@ mod tests {
         let config = create_test_config();
         
         // Initialize global coordinator
-        init_hypergraph_coordinator(config).unwrap();
+        reset_hypergraph_coordinator();
+        init_hypergraph_coordinator(config.clone()).unwrap();
+        assert!(init_hypergraph_coordinator(config.clone()).is_err());
         
         // Test global convenience functions
         register_module("global_test_module").unwrap();
@@ -202,5 +206,2965 @@ mod tests {
         // Generate health report
         let report = generate_health_report().unwrap();
         assert!(!report.is_empty());
+        
+        // A reset starts over from an empty coordinator
+        reset_hypergraph_coordinator();
+        assert!(get_hypergraph_coordinator().is_err());
+        init_hypergraph_coordinator(config).unwrap();
+        assert!(get_hypergraph_coordinator().unwrap().module_registry.read().is_empty());
+        reset_hypergraph_coordinator();
+    }
+
+    #[tokio::test]
+    async fn test_windowed_metrics() {
+        let config = create_test_config();
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(config).with_clock(clock.clone());
+        
+        coordinator.register_module("test_module").unwrap();
+        
+        // Old activity that should fall out of the window
+        coordinator.record_activity("test_module", "llm_completion", Duration::from_millis(400)).unwrap();
+        coordinator.record_error("test_module", "old error").unwrap();
+        
+        clock.advance(Duration::from_secs(600));
+        
+        // Recent activity inside the window
+        coordinator.record_activity("test_module", "embedding", Duration::from_millis(100)).unwrap();
+        coordinator.record_activity("test_module", "embedding", Duration::from_millis(300)).unwrap();
+        
+        let windowed = coordinator.windowed_metrics(Duration::from_secs(300));
+        assert_eq!(windowed.total_operations, 2);
+        assert_eq!(windowed.average_response_time, Duration::from_millis(200));
+        assert_eq!(windowed.error_rate, 0.0);
+        
+        let lifetime = coordinator.get_performance_metrics();
+        assert_eq!(lifetime.total_operations, 3);
+        
+        let wide = coordinator.windowed_metrics(Duration::from_secs(900));
+        assert_eq!(wide.total_operations, 3);
+        assert!((wide.error_rate - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_strength_normalization() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        for module in ["a", "b", "c", "d"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("a", "b", 0.2).unwrap();
+        coordinator.establish_connection("b", "c", 0.4).unwrap();
+        coordinator.establish_connection("c", "d", 0.1).unwrap();
+        
+        let key = |a: &str, b: &str| (a.to_string(), b.to_string());
+        
+        // The returned view is normalized but the live matrix is untouched
+        let view = coordinator.normalize_strengths(NormalizationMode::MaxScale, false).unwrap();
+        assert_eq!(view[&key("b", "c")], 1.0);
+        assert!(view[&key("a", "b")] > view[&key("c", "d")]);
+        assert!(view[&key("b", "c")] > view[&key("a", "b")]);
+        assert_eq!(coordinator.synergy_matrix.read()[&key("b", "c")], 0.4);
+        
+        let zscores = coordinator.normalize_strengths(NormalizationMode::ZScore, false).unwrap();
+        assert!(zscores[&key("b", "c")] > zscores[&key("a", "b")]);
+        assert!(zscores[&key("a", "b")] > zscores[&key("c", "d")]);
+        
+        // Z-scores would break the [0, 1] invariant of the live matrix
+        assert!(coordinator.normalize_strengths(NormalizationMode::ZScore, true).is_err());
+        assert_eq!(coordinator.synergy_matrix.read()[&key("b", "c")], 0.4);
+        
+        // In-place normalization rewrites the matrix preserving ordering
+        coordinator.normalize_strengths(NormalizationMode::MaxScale, true).unwrap();
+        let synergy_matrix = coordinator.synergy_matrix.read();
+        assert_eq!(synergy_matrix[&key("b", "c")], 1.0);
+        assert_eq!(synergy_matrix[&key("c", "b")], 1.0);
+        assert!(synergy_matrix[&key("a", "b")] > synergy_matrix[&key("d", "c")]);
+    }
+
+    #[tokio::test]
+    async fn test_clear_errors() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        coordinator.register_module("module_a").unwrap();
+        coordinator.register_module("module_b").unwrap();
+        coordinator.establish_connection("module_a", "module_b", 0.9).unwrap();
+        for _ in 0..20 {
+            coordinator.record_activity("module_a", "llm_completion", Duration::from_millis(10)).unwrap();
+        }
+        for i in 0..11 {
+            coordinator.record_error("module_a", &format!("error {}", i)).unwrap();
+        }
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let audit = audits.iter().find(|a| a.module_name == "module_a").unwrap();
+        assert!(matches!(audit.status, ModuleStatus::Critical));
+        
+        coordinator.clear_errors("module_a").unwrap();
+        
+        assert_eq!(coordinator.module_registry.read()["module_a"].error_count, 0);
+        assert_eq!(coordinator.windowed_metrics(Duration::from_secs(60)).error_rate, 0.0);
+        let audits = coordinator.audit_core_modules().unwrap();
+        let audit = audits.iter().find(|a| a.module_name == "module_a").unwrap();
+        assert!(matches!(audit.status, ModuleStatus::Healthy));
+        
+        assert!(coordinator.clear_errors("unknown_module").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_max_edges_eviction() {
+        let config = create_test_config();
+        let settings = HypergraphConfig {
+            max_edges: 2,
+            ..Default::default()
+        };
+        let coordinator = HypergraphCoordinator::new(config).with_settings(settings).unwrap();
+        
+        for module in ["a", "b", "c", "d"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("a", "b", 0.9).unwrap();
+        coordinator.establish_connection("b", "c", 0.3).unwrap();
+        coordinator.establish_connection("c", "d", 0.6).unwrap();
+        
+        let registry = coordinator.module_registry.read();
+        let synergy_matrix = coordinator.synergy_matrix.read();
+        
+        // The weakest edge (b <-> c) is evicted from both matrix and connection sets
+        assert_eq!(synergy_matrix.len(), 4);
+        assert!(!synergy_matrix.contains_key(&("b".to_string(), "c".to_string())));
+        assert!(!synergy_matrix.contains_key(&("c".to_string(), "b".to_string())));
+        assert!(!registry["b"].active_connections.contains("c"));
+        assert!(!registry["c"].active_connections.contains("b"));
+        assert!(registry["a"].active_connections.contains("b"));
+        assert!(registry["c"].active_connections.contains("d"));
+    }
+
+    #[tokio::test]
+    async fn test_unhealthy_dependency_flagged() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        coordinator.register_module("frontend").unwrap();
+        coordinator.register_module("backend").unwrap();
+        coordinator.register_module("cache").unwrap();
+        coordinator.establish_connection("frontend", "backend", 0.9).unwrap();
+        coordinator.establish_connection("frontend", "cache", 0.6).unwrap();
+        coordinator.establish_connection("backend", "cache", 0.6).unwrap();
+        
+        for _ in 0..20 {
+            coordinator.record_activity("backend", "llm_completion", Duration::from_millis(10)).unwrap();
+        }
+        for i in 0..11 {
+            coordinator.record_error("backend", &format!("error {}", i)).unwrap();
+        }
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let frontend = audits.iter().find(|a| a.module_name == "frontend").unwrap();
+        
+        assert!(matches!(frontend.status, ModuleStatus::Healthy));
+        assert!(frontend.issues.iter().any(|i| i.contains("Depends on unhealthy module 'backend'")));
+    }
+
+    #[tokio::test]
+    async fn test_command_dispatch() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        let requests = [
+            r#"{"method":"register","params":{"module":"client"}}"#,
+            r#"{"method":"register","params":{"module":"rag"}}"#,
+            r#"{"method":"connect","params":{"module_a":"client","module_b":"rag","strength":0.8}}"#,
+            r#"{"method":"record_activity","params":{"module":"rag","operation_type":"rag_query","duration_ms":25}}"#,
+        ];
+        for request in requests {
+            let cmd: CoordinatorCommand = serde_json::from_str(request).unwrap();
+            let response = coordinator.handle_command(cmd);
+            assert_eq!(serde_json::to_value(&response).unwrap(), serde_json::json!({"result": "ok"}));
+        }
+        
+        let cmd: CoordinatorCommand = serde_json::from_str(r#"{"method":"audit"}"#).unwrap();
+        let response = serde_json::to_string(&coordinator.handle_command(cmd)).unwrap();
+        let response: CommandResponse = serde_json::from_str(&response).unwrap();
+        match response {
+            CommandResponse::Audit { modules } => {
+                assert_eq!(modules.len(), 2);
+                assert!(modules.iter().all(|m| m.hypergraph_connections == 1));
+            }
+            other => panic!("unexpected response: {:?}", other),
+        }
+        
+        let response = coordinator.handle_command(CoordinatorCommand::Report);
+        assert!(matches!(response, CommandResponse::Report { report } if report.contains("client")));
+    }
+
+    #[tokio::test]
+    async fn test_operation_time_breakdown() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(300)).unwrap();
+        coordinator.record_activity("client", "embedding", Duration::from_millis(20)).unwrap();
+        coordinator.record_activity("rag", "embedding", Duration::from_millis(30)).unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(50)).unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(70)).unwrap();
+        
+        let breakdown = coordinator.operation_time_breakdown();
+        assert_eq!(breakdown.len(), 3);
+        assert_eq!(breakdown["llm_completion"], Duration::from_millis(300));
+        assert_eq!(breakdown["embedding"], Duration::from_millis(50));
+        assert_eq!(breakdown["rag_query"], Duration::from_millis(120));
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_audit_interval() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        let mut reinforcement = ConfigReinforcement::new(HypergraphConfig {
+            audit_interval_seconds: 300,
+            min_audit_interval_seconds: 60,
+            ..Default::default()
+        });
+        
+        coordinator.register_module("module_a").unwrap();
+        coordinator.register_module("module_b").unwrap();
+        coordinator.establish_connection("module_a", "module_b", 0.9).unwrap();
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert_eq!(reinforcement.adapt_audit_interval(&audits), Duration::from_secs(300));
+        
+        // A critical module tightens the interval down to the floor
+        for _ in 0..20 {
+            coordinator.record_activity("module_a", "llm_completion", Duration::from_millis(10)).unwrap();
+        }
+        for i in 0..11 {
+            coordinator.record_error("module_a", &format!("error {}", i)).unwrap();
+        }
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert_eq!(reinforcement.adapt_audit_interval(&audits), Duration::from_secs(150));
+        assert_eq!(reinforcement.adapt_audit_interval(&audits), Duration::from_secs(75));
+        assert_eq!(reinforcement.adapt_audit_interval(&audits), Duration::from_secs(60));
+        
+        // Recovery relaxes it back to the base interval
+        coordinator.clear_errors("module_a").unwrap();
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert_eq!(reinforcement.adapt_audit_interval(&audits), Duration::from_secs(120));
+        assert_eq!(reinforcement.adapt_audit_interval(&audits), Duration::from_secs(240));
+        assert_eq!(reinforcement.adapt_audit_interval(&audits), Duration::from_secs(300));
+        assert_eq!(reinforcement.audit_interval(), Duration::from_secs(300));
+    }
+
+    #[tokio::test]
+    async fn test_connection_ttl_expiry() {
+        let config = create_test_config();
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(config).with_clock(clock.clone());
+        
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("function").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        coordinator.establish_connection_ttl("client", "function", 0.6, Duration::from_secs(30)).unwrap();
+        
+        clock.advance(Duration::from_secs(10));
+        coordinator.audit_core_modules().unwrap();
+        assert!(coordinator.module_registry.read()["client"].active_connections.contains("function"));
+        
+        clock.advance(Duration::from_secs(30));
+        // Expiry waits for the topology to be unfrozen
+        coordinator.freeze_topology();
+        coordinator.audit_core_modules().unwrap();
+        assert!(coordinator.module_registry.read()["client"].active_connections.contains("function"));
+        coordinator.unfreeze_topology();
+        coordinator.audit_core_modules().unwrap();
+        
+        let registry = coordinator.module_registry.read();
+        let synergy_matrix = coordinator.synergy_matrix.read();
+        assert!(!registry["client"].active_connections.contains("function"));
+        assert!(registry["function"].active_connections.is_empty());
+        assert!(!synergy_matrix.contains_key(&("client".to_string(), "function".to_string())));
+        assert!(!synergy_matrix.contains_key(&("function".to_string(), "client".to_string())));
+        // Permanent edges are unaffected
+        assert!(registry["client"].active_connections.contains("rag"));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_regression_gate() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("config").unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        coordinator.establish_connection("client", "config", 0.8).unwrap();
+        let baseline = coordinator.snapshot();
+        
+        // A small weakening stays within tolerance
+        coordinator.establish_connection("client", "rag", 0.75).unwrap();
+        let current = coordinator.snapshot();
+        assert!(assert_no_regression(&baseline, &current, 0.05).is_ok());
+        
+        // A large weakening fails the gate for the affected modules
+        coordinator.establish_connection("client", "rag", 0.2).unwrap();
+        let current = coordinator.snapshot();
+        let regressions = assert_no_regression(&baseline, &current, 0.05).unwrap_err();
+        let mut modules: Vec<&str> = regressions.iter().map(|r| r.module.as_str()).collect();
+        modules.sort();
+        assert_eq!(modules, vec!["client", "rag"]);
+        assert!(regressions.iter().all(|r| r.current_score < r.baseline_score));
+    }
+
+    #[tokio::test]
+    async fn test_per_module_staleness_window() {
+        let config = create_test_config();
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(config).with_clock(clock.clone());
+        
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("batch_indexer").unwrap();
+        coordinator.establish_connection("client", "batch_indexer", 0.8).unwrap();
+        coordinator.set_staleness_window("batch_indexer", Duration::from_secs(3600)).unwrap();
+        assert!(coordinator.set_staleness_window("unknown", Duration::from_secs(1)).is_err());
+        
+        clock.advance(Duration::from_secs(600));
+        let audits = coordinator.audit_core_modules().unwrap();
+        
+        let is_stale = |name: &str| {
+            audits.iter()
+                .find(|a| a.module_name == name)
+                .unwrap()
+                .issues
+                .iter()
+                .any(|i| i == "No recent activity detected")
+        };
+        assert!(is_stale("client"));
+        assert!(!is_stale("batch_indexer"));
+    }
+
+    #[tokio::test]
+    async fn test_enabled_features() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        let features = coordinator.enabled_features();
+        assert!(features.decay);
+        assert!(features.auto_healing);
+        assert!(!features.edge_eviction);
+        assert!(features.circuit_breakers);
+        
+        let settings = HypergraphConfig {
+            enable_auto_healing: false,
+            connection_strength_decay: 1.0,
+            max_edges: 16,
+            max_module_errors: 0,
+            ..Default::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        assert_eq!(coordinator.enabled_features(), FeatureFlags {
+            decay: false,
+            auto_healing: false,
+            edge_eviction: true,
+            persistence: false,
+            circuit_breakers: false,
+        });
+        
+        let report = coordinator.generate_health_report().unwrap();
+        assert!(report.contains("auto-healing: off"));
+        assert!(report.contains("edge eviction: on"));
+        assert!(report.contains("circuit breakers: off"));
+    }
+
+    #[tokio::test]
+    async fn test_backfill_counters() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.9).unwrap();
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(10)).unwrap();
+        
+        coordinator.backfill("client", 1000, 40, 2048, BackfillMode::Additive).unwrap();
+        {
+            let registry = coordinator.module_registry.read();
+            assert_eq!(registry["client"].message_count, 1001);
+            assert_eq!(registry["client"].error_count, 40);
+            assert_eq!(registry["client"].memory_usage, 2048);
+        }
+        assert_eq!(coordinator.get_performance_metrics().total_operations, 1001);
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let client = audits.iter().find(|a| a.module_name == "client").unwrap();
+        assert!(matches!(client.status, ModuleStatus::Critical));
+        
+        coordinator.backfill("client", 500, 5, 1024, BackfillMode::Absolute).unwrap();
+        {
+            let registry = coordinator.module_registry.read();
+            assert_eq!(registry["client"].message_count, 500);
+            assert_eq!(registry["client"].error_count, 5);
+            assert_eq!(registry["client"].memory_usage, 1024);
+        }
+        assert_eq!(coordinator.get_performance_metrics().total_operations, 500);
+        
+        assert!(coordinator.backfill("unknown", 1, 0, 0, BackfillMode::Additive).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_audit_confidence() {
+        let config = create_test_config();
+        let coordinator = HypergraphCoordinator::new(config);
+        
+        coordinator.register_module("busy").unwrap();
+        coordinator.register_module("quiet").unwrap();
+        coordinator.register_module("idle").unwrap();
+        coordinator.establish_connection("busy", "quiet", 0.2).unwrap();
+        
+        for _ in 0..3 {
+            coordinator.record_activity("quiet", "embedding", Duration::from_millis(5)).unwrap();
+        }
+        coordinator.backfill("busy", 30_000, 0, 0, BackfillMode::Additive).unwrap();
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let find = |name: &str| audits.iter().find(|a| a.module_name == name).unwrap();
+        let (busy, quiet, idle) = (find("busy"), find("quiet"), find("idle"));
+        
+        assert_eq!(idle.confidence, 0.0);
+        assert!(quiet.confidence > 0.0 && quiet.confidence < 0.2);
+        assert!(busy.confidence > 0.99);
+        
+        // Low-confidence scores are pulled toward the neutral threshold
+        let threshold = 0.6;
+        assert_eq!(busy.synergy_score, quiet.synergy_score);
+        assert!(quiet.confidence_weighted_score(threshold) > busy.confidence_weighted_score(threshold));
+        assert_eq!(idle.confidence_weighted_score(threshold), threshold);
+    }
+
+    #[tokio::test]
+    async fn test_report_canonical_ordering() {
+        let build = |order: &[&str]| {
+            let settings = HypergraphConfig {
+                report_order: ReportOrder::Alphabetical,
+                ..Default::default()
+            };
+            let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+            for module in order {
+                coordinator.register_module(module).unwrap();
+            }
+            coordinator.establish_connection("client", "rag", 0.8).unwrap();
+            coordinator.generate_health_report().unwrap()
+        };
+        
+        let report_a = build(&["rag", "config", "client"]);
+        let report_b = build(&["client", "rag", "config"]);
+        assert_eq!(report_a, report_b);
+        
+        let client = report_a.find("client (").unwrap();
+        let config = report_a.find("config (").unwrap();
+        let rag = report_a.find("rag (").unwrap();
+        assert!(client < config && config < rag);
+        
+        let settings = HypergraphConfig {
+            report_order: ReportOrder::Severity,
+            ..Default::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        coordinator.register_module("alpha").unwrap();
+        coordinator.register_module("beta").unwrap();
+        coordinator.register_module("gamma").unwrap();
+        coordinator.establish_connection("alpha", "beta", 0.9).unwrap();
+        let report = coordinator.generate_health_report().unwrap();
+        // The disconnected module is listed before the healthy ones
+        assert!(report.find("gamma (").unwrap() < report.find("alpha (").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_undeclared_operation_types() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module_with_ops("rag", &["rag_query", "embedding"]).unwrap();
+        coordinator.register_module("client").unwrap();
+        
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(10)).unwrap();
+        coordinator.record_activity("rag", "llm_completion", Duration::from_millis(10)).unwrap();
+        // Modules without declarations accept any operation type
+        coordinator.record_activity("client", "anything", Duration::from_millis(10)).unwrap();
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let rag = audits.iter().find(|a| a.module_name == "rag").unwrap();
+        let client = audits.iter().find(|a| a.module_name == "client").unwrap();
+        assert!(rag.issues.iter().any(|i| i.contains("undeclared operation types: llm_completion")));
+        assert!(!client.issues.iter().any(|i| i.contains("undeclared")));
+        assert_eq!(coordinator.module_registry.read()["rag"].message_count, 2);
+        
+        let settings = HypergraphConfig {
+            undeclared_operation_policy: UndeclaredOperationPolicy::Error,
+            ..Default::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        coordinator.register_module_with_ops("rag", &["rag_query"]).unwrap();
+        assert!(coordinator.record_activity("rag", "llm_completion", Duration::from_millis(10)).is_err());
+        assert_eq!(coordinator.module_registry.read()["rag"].message_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_performance_series() {
+        let settings = HypergraphConfig {
+            performance_series_capacity: 3,
+            ..Default::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        
+        assert!(coordinator.performance_series().is_empty());
+        
+        coordinator.audit_core_modules().unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        coordinator.audit_core_modules().unwrap();
+        
+        let series = coordinator.performance_series();
+        assert_eq!(series.len(), 2);
+        assert_eq!(series.synergy_coefficient[0], 0.0);
+        assert!(series.synergy_coefficient[1] > 0.0);
+        
+        for _ in 0..5 {
+            coordinator.record_activity("client", "llm_completion", Duration::from_millis(100)).unwrap();
+            coordinator.audit_core_modules().unwrap();
+        }
+        
+        let series = coordinator.performance_series();
+        assert_eq!(series.len(), 3);
+        assert_eq!(series.average_response_time.len(), 3);
+        assert_eq!(series.memory_efficiency.len(), 3);
+        assert_eq!(series.synergy_coefficient.len(), 3);
+        assert!(series.average_response_time[2] > series.average_response_time[0]);
+    }
+
+    #[tokio::test]
+    async fn test_panicking_sampler_is_contained() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        
+        coordinator.set_memory_sampler("client", || 4096).unwrap();
+        coordinator.set_memory_sampler("rag", || panic!("sampler bug")).unwrap();
+        assert!(coordinator.set_memory_sampler("unknown", || 0).is_err());
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert_eq!(audits.len(), 2);
+        {
+            let registry = coordinator.module_registry.read();
+            assert_eq!(registry["client"].memory_usage, 4096);
+            assert_eq!(registry["rag"].memory_usage, 0);
+        }
+        
+        // The coordinator keeps working after the panic
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        coordinator.establish_connection("client", "rag", 0.7).unwrap();
+        assert!(coordinator.generate_health_report().is_ok());
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_server_report_json() {
+        let coordinator = Arc::new(HypergraphCoordinator::new(create_test_config()));
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(crate::hypergraph_server::serve_listener(coordinator, listener));
+        
+        let client = reqwest::Client::new();
+        let res = client.get(format!("http://{addr}/report.json")).send().await.unwrap();
+        assert_eq!(res.status(), 200);
+        let body: serde_json::Value = res.json().await.unwrap();
+        assert_eq!(body["modules"].as_array().unwrap().len(), 2);
+        assert!(body["metrics"]["synergy_coefficient"].as_f64().unwrap() > 0.0);
+        
+        let res = client.get(format!("http://{addr}/modules/missing")).send().await.unwrap();
+        assert_eq!(res.status(), 404);
+        
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_non_finite_strength_is_contained() {
+        let build = |policy: NonFiniteStrengthPolicy| {
+            let settings = HypergraphConfig {
+                non_finite_strengths: policy,
+                ..Default::default()
+            };
+            let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+            for module in ["a", "b", "c"] {
+                coordinator.register_module(module).unwrap();
+            }
+            coordinator.establish_connection("a", "b", 0.8).unwrap();
+            coordinator.establish_connection("a", "c", 0.8).unwrap();
+            coordinator.synergy_matrix.write().insert(("a".to_string(), "c".to_string()), f64::NAN);
+            coordinator
+        };
+        
+        let coordinator = build(NonFiniteStrengthPolicy::TreatAsZero);
+        let metrics = coordinator.get_performance_metrics();
+        assert!(metrics.synergy_coefficient.is_finite());
+        let audits = coordinator.audit_core_modules().unwrap();
+        let a = audits.iter().find(|a| a.module_name == "a").unwrap();
+        assert!((a.synergy_score - (0.5 + 0.4 * 0.5)).abs() < 1e-9);
+        assert!(audits.iter().all(|a| (0.0..=1.0).contains(&a.synergy_score)));
+        
+        let coordinator = build(NonFiniteStrengthPolicy::Skip);
+        let audits = coordinator.audit_core_modules().unwrap();
+        let a = audits.iter().find(|a| a.module_name == "a").unwrap();
+        assert!((a.synergy_score - (0.5 + 0.8 * 0.5)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_module_readiness() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("idle").unwrap();
+        coordinator.establish_connection("client", "rag", 0.9).unwrap();
+        
+        assert_eq!(coordinator.module_readiness("client").unwrap(), Readiness::Ready);
+        assert_eq!(coordinator.module_readiness("idle").unwrap(), Readiness::Degraded);
+        assert!(coordinator.module_readiness("unknown").is_err());
+        
+        coordinator.set_lifecycle("client", ModuleLifecycle::Draining).unwrap();
+        assert_eq!(coordinator.module_readiness("client").unwrap(), Readiness::NotReady);
+        coordinator.set_lifecycle("client", ModuleLifecycle::Starting).unwrap();
+        assert_eq!(coordinator.module_readiness("client").unwrap(), Readiness::NotReady);
+        coordinator.set_lifecycle("client", ModuleLifecycle::Stopped).unwrap();
+        assert_eq!(coordinator.module_readiness("client").unwrap(), Readiness::Excluded);
+        
+        for _ in 0..20 {
+            coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        }
+        for i in 0..11 {
+            coordinator.record_error("rag", &format!("error {}", i)).unwrap();
+        }
+        coordinator.audit_core_modules().unwrap();
+        assert_eq!(coordinator.module_readiness("rag").unwrap(), Readiness::NotReady);
+        coordinator.set_lifecycle("rag", ModuleLifecycle::Stopped).unwrap();
+        assert_eq!(coordinator.module_readiness("rag").unwrap(), Readiness::Excluded);
+    }
+
+    #[tokio::test]
+    async fn test_error_spike_detection() {
+        let config = create_test_config();
+        let clock = Arc::new(ManualClock::new());
+        let settings = HypergraphConfig {
+            error_spike_threshold: 3,
+            error_spike_window_seconds: 60,
+            ..Default::default()
+        };
+        let coordinator = HypergraphCoordinator::new(config)
+            .with_settings(settings).unwrap()
+            .with_clock(clock.clone());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.9).unwrap();
+        for _ in 0..50 {
+            coordinator.record_activity("client", "llm_completion", Duration::from_millis(5)).unwrap();
+        }
+        
+        let has_spike = |coordinator: &HypergraphCoordinator| {
+            coordinator.audit_core_modules().unwrap()
+                .iter()
+                .find(|a| a.module_name == "client")
+                .unwrap()
+                .issues
+                .iter()
+                .any(|i| i.starts_with("Error spike"))
+        };
+        
+        // Errors spread out over time never exceed the per-window threshold
+        for i in 0..3 {
+            coordinator.record_error("client", &format!("slow error {}", i)).unwrap();
+            clock.advance(Duration::from_secs(61));
+        }
+        assert!(!has_spike(&coordinator));
+        
+        // A burst inside one window fires even though the total stays below critical
+        for i in 0..4 {
+            coordinator.record_error("client", &format!("burst error {}", i)).unwrap();
+        }
+        assert_eq!(coordinator.module_errors_in_window("client", Duration::from_secs(60)), 4);
+        assert!(has_spike(&coordinator));
+    }
+
+    #[tokio::test]
+    async fn test_frozen_topology() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        
+        coordinator.freeze_topology();
+        assert!(coordinator.is_topology_frozen());
+        assert!(coordinator.establish_connection("client", "rag", 0.8).is_err());
+        assert!(coordinator.register_module("session").is_err());
+        assert!(coordinator.synergy_matrix.read().is_empty());
+        assert_eq!(coordinator.module_registry.read().len(), 2);
+        
+        // Metrics keep flowing while frozen
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(10)).unwrap();
+        coordinator.record_error("client", "still recorded").unwrap();
+        {
+            let registry = coordinator.module_registry.read();
+            assert_eq!(registry["client"].message_count, 1);
+            assert_eq!(registry["client"].error_count, 1);
+        }
+        
+        coordinator.unfreeze_topology();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        assert_eq!(coordinator.synergy_matrix.read().len(), 2);
+    }
+
+    #[test]
+    fn test_concurrent_init_or_get() {
+        static CELL: RwLock<Option<Arc<HypergraphCoordinator>>> = parking_lot::const_rwlock(None);
+        
+        let handles: Vec<_> = (0..8)
+            .map(|_| std::thread::spawn(|| init_or_get_in(&CELL, create_test_config())))
+            .collect();
+        let coordinators: Vec<Arc<HypergraphCoordinator>> = handles.into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+        
+        assert!(coordinators.iter().all(|c| Arc::ptr_eq(c, &coordinators[0])));
+        coordinators[0].register_module("shared").unwrap();
+        assert!(init_or_get_in(&CELL, create_test_config()).module_registry.read().contains_key("shared"));
+    }
+
+    #[tokio::test]
+    async fn test_report_max_width() {
+        let settings = HypergraphConfig {
+            report_max_width: 60,
+            ..Default::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        let long_name = "an_extremely_long_module_name_used_by_some_deeply_nested_subsystem";
+        coordinator.register_module(long_name).unwrap();
+        coordinator.register_module_with_ops("client", &["llm_completion"]).unwrap();
+        for op in ["streaming_completion_with_tools", "speculative_embedding_prefetch"] {
+            coordinator.record_activity("client", op, Duration::from_millis(5)).unwrap();
+        }
+        
+        let report = coordinator.generate_health_report().unwrap();
+        let detail_start = report.find("Detailed Module Analysis").unwrap();
+        for line in report[detail_start..].lines() {
+            assert!(line.chars().count() <= 60, "line too wide: {:?}", line);
+        }
+        assert!(report.contains("an_extremely_long_module…"));
+        assert!(!report.contains(long_name));
+        // Wrapped continuation lines keep the bullet indentation
+        assert!(report.lines().any(|line| line.starts_with("     ") && !line.trim().is_empty()));
+        
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module(long_name).unwrap();
+        assert!(coordinator.generate_health_report().unwrap().contains(long_name));
+    }
+
+    #[tokio::test]
+    async fn test_export_changed_since() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "rag", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        
+        let (audits, marker) = coordinator.export_changed_since(ExportMarker::default()).unwrap();
+        assert_eq!(audits.len(), 4);
+        
+        let (audits, marker) = coordinator.export_changed_since(marker).unwrap();
+        assert!(audits.is_empty());
+        
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        let (audits, marker) = coordinator.export_changed_since(marker).unwrap();
+        assert_eq!(audits.len(), 1);
+        assert_eq!(audits[0].module_name, "rag");
+        
+        coordinator.establish_connection("client", "config", 0.8).unwrap();
+        let (audits, _) = coordinator.export_changed_since(marker).unwrap();
+        let mut names: Vec<&str> = audits.iter().map(|a| a.module_name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["client", "config"]);
+    }
+
+    #[tokio::test]
+    async fn test_rag_retrieval_quality() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("client").unwrap();
+        coordinator.establish_connection("rag", "client", 0.8).unwrap();
+        assert!(coordinator.retrieval_quality().is_none());
+        
+        coordinator.record_rag_retrieval(0.9, 5, Duration::from_millis(20)).unwrap();
+        coordinator.record_rag_retrieval(0.7, 3, Duration::from_millis(20)).unwrap();
+        let quality = coordinator.retrieval_quality().unwrap();
+        assert_eq!(quality.samples, 2);
+        assert!((quality.average_top_score - 0.8).abs() < 1e-9);
+        assert_eq!(quality.average_hits, 4.0);
+        assert_eq!(quality.empty_rate, 0.0);
+        assert_eq!(coordinator.module_registry.read()["rag"].message_count, 2);
+        
+        let low_quality = |coordinator: &HypergraphCoordinator| {
+            coordinator.audit_core_modules().unwrap()
+                .iter()
+                .find(|a| a.module_name == "rag")
+                .unwrap()
+                .issues
+                .iter()
+                .any(|i| i.starts_with("Low retrieval quality"))
+        };
+        assert!(!low_quality(&coordinator));
+        
+        for _ in 0..8 {
+            coordinator.record_rag_retrieval(0.0, 0, Duration::from_millis(20)).unwrap();
+        }
+        let quality = coordinator.retrieval_quality().unwrap();
+        assert!((quality.average_top_score - 0.16).abs() < 1e-9);
+        assert_eq!(quality.empty_rate, 0.8);
+        assert!(low_quality(&coordinator));
+    }
+
+    #[tokio::test]
+    async fn test_report_color_output() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("config").unwrap();
+        coordinator.establish_connection("client", "config", 0.8).unwrap();
+        coordinator.register_module("isolated").unwrap();
+        
+        let plain = coordinator.render_health_report(false).unwrap();
+        assert!(!plain.contains('\u{1b}'));
+        
+        let colored = coordinator.render_health_report(true).unwrap();
+        assert!(colored.contains('\u{1b}'));
+        
+        let settings = HypergraphConfig { force_color: true, ..Default::default() };
+        let forced = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        forced.register_module("client").unwrap();
+        assert!(forced.generate_health_report().unwrap().contains('\u{1b}'));
+        
+        // Stdout detection only applies to printed reports
+        assert!(report_color(false, false));
+        assert!(!report_color(false, true));
+        assert!(report_color(true, true));
+        match forced.handle_command(CoordinatorCommand::Report) {
+            CommandResponse::Report { report } => assert!(!report.contains('\u{1b}')),
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_current_audit_cached_per_tick() {
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        coordinator.register_module("client").unwrap();
+        
+        let first = coordinator.current_audit().unwrap();
+        let second = coordinator.current_audit().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(coordinator.performance_series().len(), 1);
+        
+        let tick = Duration::from_secs(HypergraphConfig::default().audit_interval_seconds);
+        clock.advance(tick);
+        let third = coordinator.current_audit().unwrap();
+        assert!(!Arc::ptr_eq(&first, &third));
+        assert_eq!(coordinator.performance_series().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_weighted_synergy_coefficient() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("config").unwrap();
+        coordinator.register_module("logger").unwrap();
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        
+        let scores: Vec<f64> = coordinator.snapshot().modules.values().map(|m| m.synergy_score).collect();
+        let plain = coordinator.get_performance_metrics().synergy_coefficient;
+        assert!((plain - scores.iter().sum::<f64>() / 3.0).abs() < 1e-9);
+        
+        coordinator.set_module_weight("client", 4.0).unwrap();
+        let weighted = coordinator.get_performance_metrics().synergy_coefficient;
+        let expected = (scores[0] * 4.0 + scores[1] + scores[2]) / 6.0;
+        assert!((weighted - expected).abs() < 1e-9);
+        assert!(weighted > plain);
+        assert!((coordinator.snapshot().synergy_coefficient - expected).abs() < 1e-9);
+        
+        assert!(coordinator.set_module_weight("client", -1.0).is_err());
+        assert!(coordinator.set_module_weight("missing", 1.0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_attention_allocation() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        assert!(coordinator.attention_allocation().is_empty());
+        
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("config").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        coordinator.record_activity("client", "session_management", Duration::from_millis(10)).unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(10)).unwrap();
+        for _ in 0..3 {
+            coordinator.record_error("rag", "index unavailable").unwrap();
+        }
+        
+        let allocation = coordinator.attention_allocation();
+        assert_eq!(allocation.len(), 3);
+        assert_eq!(allocation[0].0, "rag");
+        let share = |name: &str| allocation.iter().find(|(n, _)| n == name).unwrap().1;
+        assert!(share("rag") > share("client"));
+        assert!(share("rag") > share("config"));
+        let total: f64 = allocation.iter().map(|(_, a)| a).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_correlated_failures() {
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("repl").unwrap();
+        
+        for step in 0..6 {
+            if step % 2 == 0 {
+                for _ in 0..2 {
+                    coordinator.record_error("client", "upstream timeout").unwrap();
+                    coordinator.record_error("rag", "upstream timeout").unwrap();
+                }
+            } else {
+                coordinator.record_error("repl", "bad input").unwrap();
+            }
+            clock.advance(Duration::from_secs(1));
+        }
+        
+        let pairs = coordinator.correlated_failures(Duration::from_secs(60));
+        assert_eq!(pairs.len(), 1);
+        let (a, b, correlation) = &pairs[0];
+        assert_eq!((a.as_str(), b.as_str()), ("client", "rag"));
+        assert!(*correlation > 0.99);
+    }
+
+    #[tokio::test]
+    async fn test_autosave_interval() {
+        let clock = Arc::new(ManualClock::new());
+        let store = Arc::new(MemorySnapshotStore::new());
+        let settings = HypergraphConfig { autosave_interval_seconds: 10, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config())
+            .with_settings(settings).unwrap()
+            .with_clock(clock.clone())
+            .with_snapshot_store(store.clone());
+        coordinator.register_module("client").unwrap();
+        assert!(coordinator.enabled_features().persistence);
+        assert!(coordinator.last_save_time().is_none());
+        
+        coordinator.audit_core_modules().unwrap();
+        assert_eq!(store.snapshots().len(), 1);
+        let first_save = coordinator.last_save_time().unwrap();
+        
+        clock.advance(Duration::from_secs(5));
+        coordinator.audit_core_modules().unwrap();
+        assert_eq!(store.snapshots().len(), 1);
+        
+        clock.advance(Duration::from_secs(5));
+        coordinator.register_module("config").unwrap();
+        coordinator.audit_core_modules().unwrap();
+        let snapshots = store.snapshots();
+        assert_eq!(snapshots.len(), 2);
+        assert!(snapshots[1].modules.contains_key("config"));
+        assert_eq!(coordinator.last_save_time().unwrap() - first_save, Duration::from_secs(10));
+        
+        let disabled_store = Arc::new(MemorySnapshotStore::new());
+        let disabled = HypergraphCoordinator::new(create_test_config()).with_snapshot_store(disabled_store.clone());
+        disabled.audit_core_modules().unwrap();
+        assert!(disabled_store.snapshots().is_empty());
+        assert!(!disabled.enabled_features().persistence);
+    }
+
+    #[tokio::test]
+    async fn test_modules_with_status() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "rag", "repl", "function"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        for peer in ["client", "config"] {
+            coordinator.establish_directed_connection(peer, "rag", 0.9).unwrap();
+            coordinator.establish_directed_connection("rag", peer, 0.1).unwrap();
+        }
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(10)).unwrap();
+        for _ in 0..3 {
+            coordinator.record_error("rag", "index unavailable").unwrap();
+        }
+        for _ in 0..11 {
+            coordinator.record_error("function", "tool crashed").unwrap();
+        }
+        
+        let with_status = |status| coordinator.modules_with_status(status).unwrap();
+        assert_eq!(with_status(ModuleStatus::Healthy), vec!["client", "config"]);
+        assert_eq!(with_status(ModuleStatus::Warning), vec!["rag"]);
+        assert_eq!(with_status(ModuleStatus::Disconnected), vec!["repl"]);
+        assert_eq!(with_status(ModuleStatus::Critical), vec!["function"]);
+        
+        // Statuses are not served from the per-tick audit cache
+        coordinator.establish_connection("repl", "client", 0.9).unwrap();
+        assert!(with_status(ModuleStatus::Disconnected).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_report_survives_audit_failure() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("config").unwrap();
+        coordinator.establish_connection("client", "config", 0.8).unwrap();
+        coordinator.set_memory_sampler("client", || panic!("allocator stats unavailable")).unwrap();
+        
+        let report = coordinator.generate_health_report().unwrap();
+        assert!(report.contains("Audit Errors"));
+        assert!(report.contains("allocator stats unavailable"));
+        assert!(report.contains("client (Synergy"));
+        assert!(report.contains("config (Synergy"));
+        assert_eq!(coordinator.last_audit_errors().len(), 1);
+        
+        let healthy = HypergraphCoordinator::new(create_test_config());
+        healthy.register_module("client").unwrap();
+        assert!(!healthy.generate_health_report().unwrap().contains("Audit Errors"));
+    }
+
+    #[tokio::test]
+    async fn test_dependency_health_propagation() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("repl", "config", 0.9).unwrap();
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        coordinator.declare_dependency("repl", "client").unwrap();
+        assert!(coordinator.declare_dependency("repl", "repl").is_err());
+        assert!(coordinator.declare_dependency("repl", "missing").is_err());
+        
+        let status_of = |name: &str| {
+            coordinator.audit_core_modules().unwrap()
+                .into_iter()
+                .find(|a| a.module_name == name)
+                .unwrap()
+        };
+        assert_eq!(status_of("repl").status, ModuleStatus::Healthy);
+        
+        for _ in 0..11 {
+            coordinator.record_error("client", "provider outage").unwrap();
+        }
+        let repl = status_of("repl");
+        assert_eq!(repl.status, ModuleStatus::Warning);
+        assert!(repl.issues.contains(&"Dependency 'client' is Critical".to_string()));
+        assert_eq!(status_of("config").status, ModuleStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_approx_eq() {
+        let sum = 0.1 + 0.2;
+        assert_ne!(sum, 0.3);
+        assert!(approx_eq(sum, 0.3));
+        assert!(!approx_eq(0.3, 0.3001));
+        assert!(approx_eq_within(0.3, 0.3001, 1e-3));
+        
+        // A score that only misses a threshold by rounding error is not flagged
+        let score = 0.7 - 0.4;
+        assert!(score < 0.3);
+        assert!(!below_threshold(score, 0.3, DEFAULT_EPSILON));
+        assert!(below_threshold(0.29, 0.3, DEFAULT_EPSILON));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn test_openmetrics_trace_exemplars() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("config").unwrap();
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(3)).unwrap();
+        coordinator.record_activity_traced("client", "llm_completion", Duration::from_millis(420), "4bf92f3577b34da6").unwrap();
+        coordinator.record_activity("config", "validation", Duration::from_millis(2)).unwrap();
+        
+        let output = crate::hypergraph_metrics::render_openmetrics(&coordinator);
+        assert!(output.contains(
+            "caichat_module_latency_seconds_bucket{module=\"client\",le=\"0.5\"} 2 # {trace_id=\"4bf92f3577b34da6\"} 0.42"
+        ));
+        assert!(output.contains("caichat_module_latency_seconds_count{module=\"client\"} 2"));
+        assert!(output.contains("caichat_module_latency_seconds_bucket{module=\"config\",le=\"0.005\"} 1\n"));
+        assert!(!output.lines().any(|l| l.contains("module=\"config\"") && l.contains("trace_id")));
+        assert!(output.ends_with("# EOF\n"));
+    }
+
+    #[cfg(feature = "chaos")]
+    #[tokio::test]
+    async fn test_inject_failure() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "rag", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        coordinator.establish_connection("client", "rag", 0.9).unwrap();
+        coordinator.establish_connection("config", "rag", 0.9).unwrap();
+        coordinator.establish_connection("repl", "config", 0.9).unwrap();
+        coordinator.establish_connection("repl", "rag", 0.9).unwrap();
+        assert!(coordinator.inject_failure("missing", FailureKind::Disconnect).is_err());
+        
+        coordinator.inject_failure("client", FailureKind::BurstErrors(11)).unwrap();
+        coordinator.inject_failure("config", FailureKind::Disconnect).unwrap();
+        coordinator.inject_failure("rag", FailureKind::HighLatency(Duration::from_secs(30))).unwrap();
+        coordinator.inject_failure("repl", FailureKind::LoadSpike(0.95)).unwrap();
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let audit = |name: &str| audits.iter().find(|a| a.module_name == name).unwrap();
+        assert_eq!(audit("client").status, ModuleStatus::Critical);
+        assert_eq!(audit("config").status, ModuleStatus::Disconnected);
+        assert!(!coordinator.synergy_matrix.read().keys().any(|(a, b)| a == "config" || b == "config"));
+        assert!(audit("rag").issues.contains(&"High cognitive load detected".to_string()));
+        assert!(audit("repl").issues.contains(&"High cognitive load detected".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_history_time_retention() {
+        let clock = Arc::new(ManualClock::new());
+        let settings = HypergraphConfig { retain_history_for_seconds: 60, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config())
+            .with_settings(settings).unwrap()
+            .with_clock(clock.clone());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("config").unwrap();
+        
+        coordinator.audit_core_modules().unwrap();
+        let first_audit = clock.now();
+        clock.advance(Duration::from_secs(45));
+        coordinator.audit_core_modules().unwrap();
+        assert_eq!(coordinator.audit_history.read().len(), 4);
+        
+        clock.advance(Duration::from_secs(30));
+        coordinator.audit_core_modules().unwrap();
+        let history = coordinator.audit_history.read();
+        assert_eq!(history.len(), 4);
+        assert!(history.iter().all(|audit| audit.last_checked > first_audit));
+    }
+
+    #[tokio::test]
+    async fn test_startup_order() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["repl", "client", "config", "rag", "function"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.declare_dependency("repl", "client").unwrap();
+        coordinator.declare_dependency("repl", "rag").unwrap();
+        coordinator.declare_dependency("client", "config").unwrap();
+        coordinator.declare_dependency("rag", "client").unwrap();
+        
+        let order = coordinator.startup_order().unwrap();
+        assert_eq!(order, vec!["config", "client", "rag", "repl", "function"]);
+        let position = |name: &str| order.iter().position(|m| m == name).unwrap();
+        assert!(position("config") < position("client"));
+        assert!(position("client") < position("rag"));
+        assert!(position("rag") < position("repl"));
+        
+        coordinator.declare_dependency("config", "repl").unwrap();
+        let err = coordinator.startup_order().unwrap_err();
+        assert!(err.to_string().contains("Dependency cycle"));
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_custom_gauges_exported() {
+        let coordinator = Arc::new(HypergraphCoordinator::new(create_test_config()));
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.set_gauge("rag", "cache_hit_rate", 0.75).unwrap();
+        coordinator.set_gauge("rag", "queue_depth", 3.0).unwrap();
+        coordinator.set_gauge("rag", "queue_depth", 4.0).unwrap();
+        assert!(coordinator.set_gauge("missing", "queue_depth", 1.0).is_err());
+        
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(crate::hypergraph_server::serve_listener(coordinator, listener));
+        let client = reqwest::Client::new();
+        
+        let body: serde_json::Value = client.get(format!("http://{addr}/report.json"))
+            .send().await.unwrap()
+            .json().await.unwrap();
+        let rag = body["modules"].as_array().unwrap()
+            .iter()
+            .find(|m| m["module_name"] == "rag")
+            .unwrap();
+        assert_eq!(rag["gauges"]["queue_depth"], 4.0);
+        assert_eq!(rag["gauges"]["cache_hit_rate"], 0.75);
+        
+        let metrics = client.get(format!("http://{addr}/metrics"))
+            .send().await.unwrap()
+            .text().await.unwrap();
+        assert!(metrics.contains("caichat_module_gauge{module=\"rag\",key=\"queue_depth\"} 4"));
+        assert!(metrics.contains("caichat_module_gauge{module=\"rag\",key=\"cache_hit_rate\"} 0.75"));
+        
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_active_but_unconnected() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("function").unwrap();
+        coordinator.register_module("serve").unwrap();
+        for _ in 0..25 {
+            coordinator.record_activity("function", "tool_call", Duration::from_millis(5)).unwrap();
+        }
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let audit = |name: &str| audits.iter().find(|a| a.module_name == name).unwrap();
+        let flagged = |name: &str| audit(name).issues.iter().any(|i| i.starts_with("Active but unconnected"));
+        
+        assert_eq!(audit("function").status, ModuleStatus::Disconnected);
+        assert!(flagged("function"));
+        assert_eq!(audit("serve").status, ModuleStatus::Disconnected);
+        assert!(!flagged("serve"));
+    }
+
+    #[tokio::test]
+    async fn test_dashboard() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "rag", "repl", "function", "serve", "render"] {
+            coordinator.register_module(module).unwrap();
+        }
+        for peer in ["config", "rag", "repl", "function"] {
+            coordinator.establish_connection("client", peer, 0.9).unwrap();
+        }
+        coordinator.establish_connection("config", "rag", 0.9).unwrap();
+        for _ in 0..12 {
+            coordinator.record_activity("client", "llm_completion", Duration::from_millis(5)).unwrap();
+        }
+        for _ in 0..11 {
+            coordinator.record_error("function", "tool crashed").unwrap();
+        }
+        
+        let dashboard = coordinator.dashboard().unwrap();
+        let count = |status| coordinator.modules_with_status(status).unwrap().len();
+        assert_eq!(dashboard.status_counts.healthy, count(ModuleStatus::Healthy));
+        assert_eq!(dashboard.status_counts.warning, count(ModuleStatus::Warning));
+        assert_eq!(dashboard.status_counts.critical, count(ModuleStatus::Critical));
+        assert_eq!(dashboard.status_counts.disconnected, count(ModuleStatus::Disconnected));
+        assert_eq!(dashboard.status_counts.critical, 1);
+        assert!(dashboard.health_score > 0.0 && dashboard.health_score < 1.0);
+        
+        assert_eq!(dashboard.top_hubs.len(), 5);
+        assert_eq!(dashboard.top_hubs[0], ("client".to_string(), 4));
+        let allocation = coordinator.attention_allocation();
+        assert_eq!(dashboard.top_struggling, allocation[..5].to_vec());
+        assert_eq!(dashboard.top_struggling[0].0, "function");
+        
+        assert!(approx_eq(dashboard.synergy_coefficient, coordinator.get_performance_metrics().synergy_coefficient));
+        assert!(approx_eq(dashboard.throughput, 12.0 / 60.0));
+        assert!(serde_json::to_value(&dashboard).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_centrality_infrastructure_hubs() {
+        let build = |exclude: bool| {
+            let settings = HypergraphConfig { exclude_infrastructure_hubs: exclude, ..Default::default() };
+            let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+            for module in ["config", "client", "rag", "repl", "function"] {
+                coordinator.register_module(module).unwrap();
+            }
+            // Auto-healing style wiring makes config the best-connected node
+            for module in ["client", "rag", "repl", "function"] {
+                coordinator.establish_connection(module, "config", 0.5).unwrap();
+            }
+            coordinator.establish_connection("client", "rag", 0.9).unwrap();
+            coordinator.establish_connection("client", "repl", 0.9).unwrap();
+            coordinator
+        };
+        
+        let marked = build(false).centrality();
+        assert_eq!(marked[0].module_name, "config");
+        assert!(marked[0].infrastructure);
+        assert!((marked[0].score - 1.0).abs() < 1e-9);
+        assert!(marked[1..].iter().all(|entry| !entry.infrastructure));
+        
+        let coordinator = build(true);
+        let excluded = coordinator.centrality();
+        assert_eq!(excluded.len(), 4);
+        assert_eq!(excluded[0].module_name, "client");
+        assert_eq!(excluded[0].degree, 3);
+        assert!(excluded.iter().all(|entry| entry.module_name != "config"));
+        assert_eq!(coordinator.dashboard().unwrap().top_hubs[0].0, "client");
+    }
+
+    #[tokio::test]
+    async fn test_auto_healing_outcomes() {
+        let reinforcement = ConfigReinforcement::new(HypergraphConfig::default());
+        let build = || {
+            let coordinator = HypergraphCoordinator::new(create_test_config());
+            coordinator.register_module("config").unwrap();
+            coordinator.register_module("client").unwrap();
+            coordinator.register_module("rag").unwrap();
+            coordinator.establish_connection("client", "config", 0.8).unwrap();
+            coordinator
+        };
+        let expected_action = HealingAction::Reconnect {
+            module: "rag".to_string(),
+            hub: "config".to_string(),
+            strength: 0.5,
+        };
+        
+        let coordinator = build();
+        let outcomes = reinforcement.apply_auto_healing_to(&coordinator).await.unwrap();
+        assert_eq!(outcomes, vec![HealingOutcome { action: expected_action.clone(), result: Ok(()) }]);
+        assert!(coordinator.module_registry.read()["rag"].active_connections.contains("config"));
+        
+        let frozen = build();
+        frozen.freeze_topology();
+        let outcomes = reinforcement.apply_auto_healing_to(&frozen).await.unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].action, expected_action);
+        assert_eq!(outcomes[0].result, Err("Hypergraph topology is frozen".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_topology_coverage() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        assert!(coordinator.topology_coverage().is_err());
+        
+        let declaration = TopologyDeclaration::from_yaml(
+            "connections:\n  - [client, config]\n  - [rag, client]\n  - [repl, rag]\n  - [config, client]\n"
+        ).unwrap();
+        coordinator.set_expected_topology(declaration);
+        for module in ["client", "config", "rag", "repl", "function"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("config", "client", 0.8).unwrap();
+        coordinator.establish_connection("client", "rag", 0.7).unwrap();
+        coordinator.establish_connection("function", "client", 0.6).unwrap();
+        
+        let pair = |a: &str, b: &str| (a.to_string(), b.to_string());
+        let coverage = coordinator.topology_coverage().unwrap();
+        assert_eq!(coverage.expected_edges, 3);
+        assert_eq!(coverage.present, vec![pair("client", "config"), pair("client", "rag")]);
+        assert_eq!(coverage.missing, vec![pair("rag", "repl")]);
+        assert_eq!(coverage.extra, vec![pair("client", "function")]);
+        assert!((coverage.ratio() - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_idle_ttl_edges() {
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        for module in ["client", "rag", "repl", "function"] {
+            coordinator.register_module(module).unwrap();
+        }
+        let idle_ttl = Duration::from_secs(60);
+        coordinator.establish_connection_idle_ttl("client", "rag", 0.8, idle_ttl).unwrap();
+        coordinator.establish_connection_idle_ttl("repl", "function", 0.8, idle_ttl).unwrap();
+        
+        // Only one endpoint of the first edge stays active over several idle periods
+        for _ in 0..5 {
+            clock.advance(Duration::from_secs(40));
+            coordinator.record_activity("client", "llm_completion", Duration::from_millis(5)).unwrap();
+            coordinator.expire_edges();
+        }
+        
+        let matrix = coordinator.synergy_matrix.read();
+        assert!(matrix.contains_key(&("client".to_string(), "rag".to_string())));
+        assert!(!matrix.contains_key(&("repl".to_string(), "function".to_string())));
+        assert!(!coordinator.module_registry.read()["repl"].active_connections.contains("function"));
+    }
+
+    #[tokio::test]
+    async fn test_matrix_evolution() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "rag", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        assert!(coordinator.matrix_evolution().is_empty());
+        
+        coordinator.establish_connection("client", "config", 0.8).unwrap();
+        coordinator.audit_core_modules().unwrap();
+        coordinator.establish_connection("client", "rag", 0.4).unwrap();
+        coordinator.establish_connection("rag", "repl", 0.6).unwrap();
+        coordinator.audit_core_modules().unwrap();
+        coordinator.establish_connection_ttl("config", "repl", 0.5, Duration::ZERO).unwrap();
+        coordinator.audit_core_modules().unwrap();
+        // Only the ("repl", "config") direction is stored
+        coordinator.establish_directed_connection("repl", "config", 0.2).unwrap();
+        coordinator.audit_core_modules().unwrap();
+        
+        let evolution = coordinator.matrix_evolution();
+        assert_eq!(evolution.len(), 4);
+        assert_eq!(evolution.iter().map(|s| s.edges).collect::<Vec<_>>(), vec![1, 3, 3, 4]);
+        assert!((evolution[3].mean_strength - 0.5).abs() < 1e-9);
+        assert!((evolution[0].mean_strength - 0.8).abs() < 1e-9);
+        assert!((evolution[1].mean_strength - 0.6).abs() < 1e-9);
+        assert!((evolution[0].density - 1.0 / 6.0).abs() < 1e-9);
+        assert!((evolution[1].density - 0.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_replay_until() {
+        let path = std::env::temp_dir().join(format!("hypergraph-replay-{}.jsonl", uuid::Uuid::new_v4()));
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        coordinator.start_recording(&path).unwrap();
+        
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(40)).unwrap();
+        clock.advance(Duration::from_secs(30));
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(10)).unwrap();
+        clock.advance(Duration::from_secs(30));
+        coordinator.register_module("repl").unwrap();
+        for _ in 0..3 {
+            coordinator.record_error("client", "rate limited").unwrap();
+        }
+        coordinator.stop_recording();
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(40)).unwrap();
+        
+        let events = read_recording(&path).unwrap();
+        assert_eq!(events.len(), 9);
+        let midpoint = events[4].at_ms;
+        assert_eq!(midpoint - events[0].at_ms, 30_000);
+        
+        let replayed = HypergraphCoordinator::replay_until(create_test_config(), &path, midpoint).unwrap();
+        {
+            let registry = replayed.module_registry.read();
+            assert_eq!(registry.keys().collect::<Vec<_>>(), vec!["client", "rag"]);
+            assert_eq!(registry["client"].message_count, 1);
+            assert_eq!(registry["client"].error_count, 0);
+            assert_eq!(registry["rag"].message_count, 1);
+            assert!(registry["client"].active_connections.contains("rag"));
+        }
+        assert_eq!(replayed.get_performance_metrics().total_operations, 2);
+        
+        let full = HypergraphCoordinator::replay_until(create_test_config(), &path, u64::MAX).unwrap();
+        assert_eq!(full.module_registry.read()["client"].error_count, 3);
+        assert_eq!(full.module_registry.read()["client"].message_count, 1);
+        assert!(full.module_registry.read().contains_key("repl"));
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_min_operations_for_critical() {
+        let settings = HypergraphConfig { min_operations_for_critical: 20, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        for _ in 0..3 {
+            coordinator.record_activity("client", "llm_completion", Duration::from_millis(5)).unwrap();
+        }
+        for _ in 0..25 {
+            coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        }
+        for _ in 0..15 {
+            coordinator.record_error("client", "provider error").unwrap();
+            coordinator.record_error("rag", "index error").unwrap();
+        }
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let audit = |name: &str| audits.iter().find(|a| a.module_name == name).unwrap();
+        assert_eq!(audit("client").status, ModuleStatus::Warning);
+        assert!(audit("client").issues.iter().any(|i| i.starts_with("Capped at Warning")));
+        assert_eq!(audit("rag").status, ModuleStatus::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_stable_json() {
+        let build = |gauges: &[(&str, f64)]| {
+            let coordinator = HypergraphCoordinator::new(create_test_config());
+            coordinator.register_module("client").unwrap();
+            coordinator.register_module("rag").unwrap();
+            coordinator.establish_connection("client", "rag", 0.7).unwrap();
+            for (key, value) in gauges {
+                coordinator.set_gauge("rag", key, *value).unwrap();
+            }
+            let audits = coordinator.audit_core_modules().unwrap();
+            let summaries: Vec<AuditSummary> = audits.iter().map(AuditSummary::from).collect();
+            to_stable_json(&summaries).unwrap()
+        };
+        
+        let first = build(&[("queue_depth", 3.0), ("cache_hit_rate", 0.1 + 0.2)]);
+        let second = build(&[("cache_hit_rate", 0.3), ("queue_depth", 3.0)]);
+        assert_eq!(first.as_bytes(), second.as_bytes());
+        assert!(first.find("\"cache_hit_rate\"").unwrap() < first.find("\"queue_depth\"").unwrap());
+        assert!(first.contains("\"cache_hit_rate\": 0.3"));
+    }
+
+    #[tokio::test]
+    async fn test_per_module_auto_healing() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["config", "client", "rag", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "config", 0.8).unwrap();
+        coordinator.set_auto_healing("rag", false).unwrap();
+        assert!(coordinator.set_auto_healing("missing", false).is_err());
+        
+        let reinforcement = ConfigReinforcement::new(HypergraphConfig::default());
+        let outcomes = reinforcement.apply_auto_healing_to(&coordinator).await.unwrap();
+        let healed: Vec<&HealingAction> = outcomes.iter().map(|o| &o.action).collect();
+        assert_eq!(healed, vec![&HealingAction::Reconnect {
+            module: "repl".to_string(),
+            hub: "config".to_string(),
+            strength: 0.5,
+        }]);
+        assert!(coordinator.module_registry.read()["rag"].active_connections.is_empty());
+        
+        // Disabled everywhere: no audit runs at all
+        let disabled = ConfigReinforcement::new(HypergraphConfig { enable_auto_healing: false, ..Default::default() });
+        let untouched = HypergraphCoordinator::new(create_test_config());
+        untouched.register_module("repl").unwrap();
+        untouched.set_auto_healing("repl", false).unwrap();
+        assert!(disabled.apply_auto_healing_to(&untouched).await.unwrap().is_empty());
+        assert_eq!(untouched.read_only_view().last_status("repl"), None);
+        
+        // An override can also opt a module in when healing is globally off
+        coordinator.set_auto_healing("rag", true).unwrap();
+        let outcomes = disabled.apply_auto_healing_to(&coordinator).await.unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert!(coordinator.module_registry.read()["rag"].active_connections.contains("config"));
+    }
+
+    #[tokio::test]
+    async fn test_recent_events_ring_buffer() {
+        let settings = HypergraphConfig { event_log_capacity: 3, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        for module in ["config", "client", "rag"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "config", 0.8).unwrap();
+        coordinator.audit_core_modules().unwrap();
+        assert_eq!(coordinator.recent_events(10).len(), 3);
+        
+        // An unchanged audit adds nothing
+        coordinator.audit_core_modules().unwrap();
+        assert_eq!(coordinator.recent_events(10).len(), 3);
+        
+        let reinforcement = ConfigReinforcement::new(HypergraphConfig::default());
+        reinforcement.apply_auto_healing_to(&coordinator).await.unwrap();
+        coordinator.audit_core_modules().unwrap();
+        
+        let events = coordinator.recent_events(10);
+        assert_eq!(events.len(), 3);
+        assert!(matches!(&events[0], CoordinatorEvent::StatusChanged { module, .. } if module == "rag"));
+        assert!(matches!(&events[1], CoordinatorEvent::Healing { module, error: None, .. } if module == "rag"));
+        assert_eq!(events[2], CoordinatorEvent::StatusChanged {
+            module: "rag".to_string(),
+            from: Some(ModuleStatus::Disconnected),
+            to: ModuleStatus::Healthy,
+        });
+        assert_eq!(coordinator.recent_events(1), events[2..].to_vec());
+        assert!(serde_json::to_string(&events).unwrap().contains("\"event\":\"healing\""));
+    }
+
+    #[tokio::test]
+    async fn test_effective_synergy() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "rag", "config", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        assert_eq!(coordinator.effective_synergy("client", "rag"), 0.0);
+        
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        coordinator.establish_connection("config", "rag", 0.8).unwrap();
+        coordinator.establish_connection("client", "repl", 0.5).unwrap();
+        coordinator.establish_connection("repl", "rag", 0.5).unwrap();
+        let (path, strength) = coordinator.strongest_path_with_strength("client", "rag", false).unwrap();
+        assert_eq!(path, vec!["client", "config", "rag"]);
+        assert!(approx_eq(strength, 0.72));
+        assert!(approx_eq(coordinator.effective_synergy("client", "rag"), 0.72));
+        
+        // A weak direct edge is reinforced by the indirect path
+        coordinator.establish_connection("client", "rag", 0.5).unwrap();
+        assert!(approx_eq(coordinator.effective_synergy("client", "rag"), 1.0 - 0.5 * 0.28));
+        assert_eq!(coordinator.strongest_path("client", "rag").unwrap(), vec!["client", "config", "rag"]);
+    }
+
+    #[tokio::test]
+    async fn test_operation_aliases() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.set_op_alias("completion", "llm_completion").unwrap();
+        coordinator.set_op_alias("chat", "llm_completion").unwrap();
+        assert!(coordinator.set_op_alias("chat", "chat").is_err());
+        assert!(coordinator.set_op_alias("chat_v2", "chat").is_err());
+        assert!(coordinator.set_op_alias("llm_completion", "completion_v2").is_err());
+        
+        for op in ["llm_completion", "completion", "chat"] {
+            coordinator.record_activity("client", op, Duration::from_millis(100)).unwrap();
+        }
+        coordinator.record_activity("client", "embedding", Duration::from_millis(10)).unwrap();
+        
+        let breakdown = coordinator.operation_time_breakdown();
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(breakdown["llm_completion"], Duration::from_millis(300));
+        assert!(!breakdown.contains_key("chat"));
+        
+        // Aliases use the canonical operation's load factor
+        let aliased = HypergraphCoordinator::new(create_test_config());
+        aliased.register_module("client").unwrap();
+        aliased.set_op_alias("chat", "llm_completion").unwrap();
+        aliased.record_activity("client", "chat", Duration::from_secs(1)).unwrap();
+        assert!(approx_eq(aliased.module_registry.read()["client"].cognitive_load, 0.08));
+    }
+
+    #[tokio::test]
+    async fn test_read_only_view() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(20)).unwrap();
+        coordinator.audit_core_modules().unwrap();
+        
+        // Only query methods are available through the view
+        let view = coordinator.read_only_view();
+        assert_eq!(view.modules(), vec!["client", "rag"]);
+        assert_eq!(view.last_status("client"), Some(ModuleStatus::Healthy));
+        assert_eq!(view.get_performance_metrics().total_operations, 1);
+        assert_eq!(view.snapshot().modules.len(), coordinator.snapshot().modules.len());
+        assert_eq!(view.centrality(), coordinator.centrality());
+        assert_eq!(view.recent_events(10), coordinator.recent_events(10));
+        assert!(approx_eq(view.effective_synergy("client", "rag"), 0.8));
+        
+        // Queries through the view leave the coordinator untouched
+        let version = coordinator.module_registry.read()["client"].version;
+        let _ = view.matrix_evolution();
+        let _ = view.operation_time_breakdown();
+        assert_eq!(coordinator.module_registry.read()["client"].version, version);
+    }
+
+    #[tokio::test]
+    async fn test_memory_efficiency_trend() {
+        assert_eq!(TrendDirection::from_series(&[0.9, 0.85, 0.8, 0.74, 0.7]), TrendDirection::Degrading);
+        assert_eq!(TrendDirection::from_series(&[0.5, 0.6, 0.7]), TrendDirection::Improving);
+        assert_eq!(TrendDirection::from_series(&[0.8, 0.8001, 0.7999, 0.8]), TrendDirection::Stable);
+        assert_eq!(TrendDirection::from_series(&[0.9, 0.1]), TrendDirection::Stable);
+        
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        let leaked = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let sampler_leak = leaked.clone();
+        coordinator.set_memory_sampler("rag", move || {
+            sampler_leak.fetch_add(500, std::sync::atomic::Ordering::SeqCst) + 500
+        }).unwrap();
+        
+        for _ in 0..5 {
+            coordinator.audit_core_modules().unwrap();
+        }
+        assert_eq!(coordinator.memory_efficiency_trend(), TrendDirection::Degrading);
+        assert!(coordinator.generate_health_report().unwrap().contains("potential leak"));
+    }
+
+    #[tokio::test]
+    async fn test_edge_operation_counts() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("embeddings").unwrap();
+        coordinator.register_module("config").unwrap();
+        assert!(coordinator.edge_operation_counts("rag", "embeddings").is_none());
+        
+        coordinator.record_interaction("rag", "embeddings", "rag_query", 0.6).unwrap();
+        coordinator.record_interaction("embeddings", "rag", "rag_query", 0.4).unwrap();
+        coordinator.record_interaction("rag", "embeddings", "index_build", 0.8).unwrap();
+        
+        let counts = coordinator.edge_operation_counts("embeddings", "rag").unwrap();
+        assert_eq!(counts.get("rag_query"), Some(&2));
+        assert_eq!(counts.get("index_build"), Some(&1));
+        assert_eq!(counts.len(), 2);
+        assert!(approx_eq(coordinator.synergy_matrix.read()[&("rag".to_string(), "embeddings".to_string())], 0.8));
+        
+        // Re-establishing the edge keeps its history
+        coordinator.establish_connection("rag", "embeddings", 0.5).unwrap();
+        assert_eq!(coordinator.edge_operation_counts("rag", "embeddings").unwrap().get("rag_query"), Some(&2));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_module_policy() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.record_activity("ghost", "rag_query", Duration::from_millis(5)).unwrap();
+        assert!(!coordinator.module_registry.read().contains_key("ghost"));
+        
+        let settings = HypergraphConfig {
+            unknown_module_policy: UnknownModulePolicy::Strict,
+            ..HypergraphConfig::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        let err = coordinator.record_activity("ghost", "rag_query", Duration::from_millis(5)).unwrap_err();
+        assert!(err.to_string().contains("ghost"));
+        assert!(!coordinator.module_registry.read().contains_key("ghost"));
+        
+        let settings = HypergraphConfig {
+            unknown_module_policy: UnknownModulePolicy::AutoRegister,
+            ..HypergraphConfig::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        coordinator.record_activity("ghost", "rag_query", Duration::from_millis(5)).unwrap();
+        assert_eq!(coordinator.module_registry.read()["ghost"].message_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_narrative_summary() {
+        assert!(HypergraphCoordinator::new(create_test_config()).narrative_summary().contains("No modules"));
+        
+        let build = || {
+            let coordinator = HypergraphCoordinator::new(create_test_config());
+            for module in ["rag", "session", "llm"] {
+                coordinator.register_module(module).unwrap();
+                coordinator.record_activity(module, "rag_query", Duration::from_millis(5)).unwrap();
+            }
+            coordinator.establish_connection("rag", "llm", 0.9).unwrap();
+            coordinator.establish_connection("session", "llm", 0.9).unwrap();
+            coordinator.establish_connection("rag", "session", 0.9).unwrap();
+            coordinator
+        };
+        let narrative = build().narrative_summary();
+        assert!(narrative.starts_with("The system is healthy. 3 of 3 modules are operating normally."), "{}", narrative);
+        
+        let coordinator = build();
+        for _ in 0..20 {
+            coordinator.record_error("session", "timeout").unwrap();
+        }
+        let narrative = coordinator.narrative_summary();
+        assert!(narrative.contains("2 of 3 modules are operating normally"), "{}", narrative);
+        assert!(narrative.contains("The session module is in critical condition"), "{}", narrative);
+        assert!(narrative.contains("Overall coherence is"), "{}", narrative);
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_snapshot() {
+        let dir = std::env::temp_dir().join(format!("caichat-snapshot-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("hypergraph.json");
+        
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        coordinator.establish_connection("rag", "llm", 0.1 + 0.2).unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        coordinator.record_error("llm", "timeout").unwrap();
+        coordinator.audit_core_modules().unwrap();
+        coordinator.save_snapshot(&path).unwrap();
+        
+        let clock = Arc::new(ManualClock::new());
+        clock.advance(Duration::from_secs(3600));
+        let restored = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        restored.load_snapshot(&path).unwrap();
+        
+        assert_eq!(restored.synergy_matrix.read()[&("rag".to_string(), "llm".to_string())], 0.1 + 0.2);
+        assert_eq!(restored.synergy_matrix.read()[&("llm".to_string(), "rag".to_string())], 0.1 + 0.2);
+        {
+            let registry = restored.module_registry.read();
+            assert_eq!(registry["rag"].message_count, 1);
+            assert_eq!(registry["llm"].error_count, 1);
+            assert!(registry["rag"].active_connections.contains("llm"));
+            assert_eq!(registry["rag"].last_activity, clock.now());
+        }
+        let history = restored.audit_history.read();
+        assert_eq!(history.len(), 2);
+        assert!(history.iter().all(|audit| audit.last_checked == clock.now()));
+        drop(history);
+        
+        std::fs::write(&path, r#"{"schema_version": 0, "modules": []}"#).unwrap();
+        let err = restored.load_snapshot(&path).unwrap_err();
+        assert!(err.to_string().contains("schema version 0"), "{}", err);
+        std::fs::write(&path, r#"{"modules": []}"#).unwrap();
+        assert!(restored.load_snapshot(&path).unwrap_err().to_string().contains("no schema version"));
+        assert_eq!(restored.module_registry.read().len(), 2);
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_health_report_json() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        coordinator.register_module("orphan").unwrap();
+        coordinator.establish_connection("rag", "llm", 0.9).unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        
+        let json = coordinator.generate_health_report_json().unwrap();
+        assert_eq!(json["metrics"]["total_operations"], 1);
+        assert_eq!(json["status_counts"]["disconnected"], 1);
+        assert_eq!(json["features"]["auto_healing"], HypergraphConfig::default().enable_auto_healing);
+        let modules = json["modules"].as_array().unwrap();
+        assert_eq!(modules.len(), 3);
+        let orphan = modules.iter().find(|m| m["module_name"] == "orphan").unwrap();
+        assert_eq!(orphan["status"], "Disconnected");
+        assert!(!orphan["recommendations"].as_array().unwrap().is_empty());
+        
+        // The text report is rendered from the same structure
+        let report = coordinator.health_report();
+        let text = coordinator.render_health_report(false).unwrap();
+        assert!(text.contains(&format!("🔌 Disconnected: {}", report.status_counts.disconnected)));
+        for module in &report.modules {
+            assert!(text.contains(&module.module_name));
+            for rec in &module.recommendations {
+                assert!(text.contains(rec.as_str()));
+            }
+        }
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_render_prometheus() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        coordinator.establish_connection("rag", "llm", 0.8).unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        coordinator.record_error("llm", "timeout").unwrap();
+        
+        let output = crate::hypergraph_metrics::render_prometheus(&coordinator);
+        for name in [
+            "caichat_total_operations",
+            "caichat_average_response_time_ms",
+            "caichat_memory_efficiency",
+            "caichat_synergy_coefficient",
+            "caichat_module_cognitive_load",
+            "caichat_module_error_count",
+            "caichat_module_hypergraph_connections",
+        ] {
+            assert!(output.contains(&format!("# HELP {name} ")), "missing HELP for {name}");
+            assert!(output.contains(&format!("# TYPE {name} ")), "missing TYPE for {name}");
+        }
+        assert!(output.contains("# TYPE caichat_total_operations counter\ncaichat_total_operations 1\n"));
+        assert!(output.contains("caichat_module_error_count{module=\"llm\"} 1\n"));
+        assert!(output.contains("caichat_module_hypergraph_connections{module=\"rag\"} 1\n"));
+        assert!(!output.contains("caichat_module_gauge"));
+    }
+
+    #[tokio::test]
+    async fn test_decay_connections() {
+        let settings = HypergraphConfig {
+            connection_strength_decay: 0.5,
+            ..HypergraphConfig::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        for module in ["a", "b", "c"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("a", "b", 0.8).unwrap();
+        coordinator.establish_connection("b", "c", 0.03).unwrap();
+        
+        // A frozen topology does not decay
+        coordinator.freeze_topology();
+        assert_eq!(coordinator.decay_connections(), 0);
+        assert_eq!(coordinator.synergy_matrix.read()[&("a".to_string(), "b".to_string())], 0.8);
+        coordinator.unfreeze_topology();
+        
+        assert_eq!(coordinator.decay_connections(), 0);
+        {
+            let matrix = coordinator.synergy_matrix.read();
+            assert!(approx_eq(matrix[&("a".to_string(), "b".to_string())], 0.4));
+            assert!(approx_eq(matrix[&("b".to_string(), "a".to_string())], 0.4));
+            assert!(approx_eq(matrix[&("c".to_string(), "b".to_string())], 0.015));
+        }
+        
+        assert_eq!(coordinator.decay_connections(), 1);
+        let matrix = coordinator.synergy_matrix.read();
+        assert_eq!(matrix.len(), 2);
+        assert!(!matrix.contains_key(&("b".to_string(), "c".to_string())));
+        assert!(!matrix.contains_key(&("c".to_string(), "b".to_string())));
+        drop(matrix);
+        let registry = coordinator.module_registry.read();
+        assert!(!registry["b"].active_connections.contains("c"));
+        assert!(registry["c"].active_connections.is_empty());
+        assert!(registry["b"].active_connections.contains("a"));
+        drop(registry);
+        
+        // The weak direction of an asymmetric pair goes first, alone
+        coordinator.establish_directed_connection("c", "b", 0.015).unwrap();
+        coordinator.establish_directed_connection("b", "c", 0.9).unwrap();
+        assert_eq!(coordinator.decay_connections(), 0);
+        assert!(!coordinator.synergy_matrix.read().contains_key(&("c".to_string(), "b".to_string())));
+        assert!(coordinator.module_registry.read()["c"].active_connections.contains("b"));
+        assert_eq!(coordinator.connections_of("b").len(), 2);
+        
+        let no_decay = HypergraphConfig {
+            connection_strength_decay: 1.0,
+            ..HypergraphConfig::default()
+        };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(no_decay).unwrap();
+        coordinator.register_module("a").unwrap();
+        coordinator.register_module("b").unwrap();
+        coordinator.establish_connection("a", "b", 0.005).unwrap();
+        assert_eq!(coordinator.decay_connections(), 0);
+        assert_eq!(coordinator.synergy_matrix.read().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_top_connections() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "rag", "llm", "config"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("rag", "client", 0.9).unwrap();
+        coordinator.establish_connection("llm", "config", 0.5).unwrap();
+        coordinator.establish_connection("client", "config", 0.5).unwrap();
+        coordinator.establish_connection("llm", "rag", 0.2).unwrap();
+        
+        let top = coordinator.top_connections(3);
+        assert_eq!(top, vec![
+            ("client".to_string(), "rag".to_string(), 0.9),
+            ("client".to_string(), "config".to_string(), 0.5),
+            ("config".to_string(), "llm".to_string(), 0.5),
+        ]);
+        assert_eq!(coordinator.top_connections(10).len(), 4);
+        assert!(coordinator.top_connections(0).is_empty());
+        assert_eq!(coordinator.read_only_view().top_connections(1), top[..1].to_vec());
+    }
+
+    #[tokio::test]
+    async fn test_connected_components() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["rag", "llm", "session"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("rag", "llm", 0.8).unwrap();
+        
+        let components = coordinator.connected_components();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0], std::collections::HashSet::from(["rag".to_string(), "llm".to_string()]));
+        assert_eq!(components[1], std::collections::HashSet::from(["session".to_string()]));
+        
+        // A lone module is Disconnected, not a split topology
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert!(audits.iter().all(|a| a.status != ModuleStatus::Critical));
+        
+        for module in ["tools", "repl", "render"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("session", "tools", 0.8).unwrap();
+        coordinator.establish_connection("rag", "repl", 0.8).unwrap();
+        coordinator.establish_connection("repl", "render", 0.8).unwrap();
+        assert_eq!(coordinator.connected_components().len(), 2);
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        for audit in &audits {
+            let isolated = audit.module_name == "session" || audit.module_name == "tools";
+            assert_eq!(audit.status == ModuleStatus::Critical, isolated, "{}", audit.module_name);
+            assert_eq!(audit.issues.iter().any(|i| i.contains("Topology split")), isolated);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reported_memory_usage_drives_efficiency() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        for _ in 0..10 {
+            coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        }
+        assert!(approx_eq(coordinator.get_performance_metrics().memory_efficiency, 1.0));
+        
+        let mut previous = 1.0;
+        for bytes in [1_000, 50_000, 1_000_000] {
+            coordinator.update_memory_usage("rag", bytes).unwrap();
+            let efficiency = coordinator.get_performance_metrics().memory_efficiency;
+            assert!(efficiency < previous, "{} bytes: {} >= {}", bytes, efficiency, previous);
+            previous = efficiency;
+        }
+        assert!(coordinator.update_memory_usage("missing", 1).is_err());
+        
+        coordinator.record_activity_with_memory("rag", "rag_query", Duration::from_millis(5), 4_096).unwrap();
+        assert_eq!(coordinator.module_registry.read()["rag"].memory_usage, 1_004_096);
+        coordinator.record_activity_with_memory("rag", "rag_query", Duration::from_millis(5), -2_000_000).unwrap();
+        assert_eq!(coordinator.module_registry.read()["rag"].memory_usage, 0);
+        assert_eq!(coordinator.module_registry.read()["rag"].message_count, 12);
+    }
+
+    #[tokio::test]
+    async fn test_configurable_load_factors() {
+        let defaults = HypergraphConfig::default();
+        assert_eq!(defaults.load_factors["llm_completion"], 0.8);
+        assert_eq!(defaults.load_factors["hypergraph_update"], 0.9);
+        
+        let mut load_factors = defaults.load_factors.clone();
+        load_factors.insert("rag_query".to_string(), 0.0);
+        let settings = HypergraphConfig { load_factors, ..HypergraphConfig::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("custom").unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_secs(1)).unwrap();
+        coordinator.record_activity("custom", "unknown_op", Duration::from_secs(1)).unwrap();
+        
+        let registry = coordinator.module_registry.read();
+        assert_eq!(registry["rag"].cognitive_load, 0.0);
+        assert!(approx_eq(registry["custom"].cognitive_load, DEFAULT_LOAD_FACTOR * 0.1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_spawn_audit_loop() {
+        let coordinator = Arc::new(HypergraphCoordinator::new(create_test_config()));
+        coordinator.register_module("config").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        coordinator.establish_connection("rag", "llm", 0.8).unwrap();
+        
+        // Paused time auto-advances, so cycles run at 0, 10, ..., 100ms
+        let handle = coordinator.clone().spawn_audit_loop(Duration::from_millis(10));
+        tokio::time::sleep(Duration::from_millis(105)).await;
+        handle.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+        
+        let audits = coordinator.audit_history.read().len();
+        assert_eq!(audits, 11 * 3);
+        let strength = coordinator.synergy_matrix.read()[&("rag".to_string(), "llm".to_string())];
+        assert!(strength < 0.8);
+        // The disconnected module was healed by reconnecting it to the hub
+        assert!(!coordinator.module_registry.read()["config"].active_connections.is_empty());
+        
+        // No further cycles run once aborted
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(coordinator.audit_history.read().len(), audits);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_to_status_transitions() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        coordinator.establish_connection("rag", "llm", 0.9).unwrap();
+        coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        coordinator.record_activity("llm", "llm_completion", Duration::from_millis(5)).unwrap();
+        
+        let mut events = coordinator.subscribe();
+        coordinator.audit_core_modules().unwrap();
+        assert!(events.try_recv().is_err(), "first audit only sets the baseline");
+        
+        for _ in 0..20 {
+            coordinator.record_error("llm", "timeout").unwrap();
+        }
+        let audits = coordinator.audit_core_modules().unwrap();
+        let before = audits.iter().find(|a| a.module_name == "llm").unwrap();
+        assert_eq!(before.status, ModuleStatus::Critical);
+        
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.module, "llm");
+        assert_eq!(event.old, ModuleStatus::Healthy);
+        assert_eq!(event.new, ModuleStatus::Critical);
+        assert_eq!(event.at, before.last_checked);
+        
+        coordinator.audit_core_modules().unwrap();
+        assert!(events.try_recv().is_err(), "no event without a change");
+    }
+
+    #[tokio::test]
+    async fn test_directed_connections() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("client").unwrap();
+        coordinator.establish_directed_connection("rag", "client", 0.9).unwrap();
+        
+        {
+            let matrix = coordinator.synergy_matrix.read();
+            assert_eq!(matrix.get(&("rag".to_string(), "client".to_string())), Some(&0.9));
+            assert!(!matrix.contains_key(&("client".to_string(), "rag".to_string())));
+            let registry = coordinator.module_registry.read();
+            assert!(registry["rag"].active_connections.contains("client"));
+            assert!(registry["client"].active_connections.contains("rag"));
+        }
+        
+        coordinator.establish_directed_connection("client", "rag", 0.3).unwrap();
+        let audits = coordinator.audit_core_modules().unwrap();
+        let score = |name: &str| audits.iter().find(|a| a.module_name == name).unwrap().synergy_score;
+        assert!(score("rag") > score("client"));
+        
+        // The symmetric method writes both directions
+        coordinator.establish_connection("client", "rag", 0.5).unwrap();
+        let matrix = coordinator.synergy_matrix.read();
+        assert_eq!(matrix[&("rag".to_string(), "client".to_string())], 0.5);
+        assert_eq!(matrix[&("client".to_string(), "rag".to_string())], 0.5);
+        drop(matrix);
+        assert_eq!(coordinator.top_connections(5).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker() {
+        let settings = HypergraphConfig {
+            max_module_errors: 3,
+            circuit_half_open_seconds: 30,
+            ..HypergraphConfig::default()
+        };
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config())
+            .with_clock(clock.clone())
+            .with_settings(settings).unwrap();
+        coordinator.register_module("llm").unwrap();
+        assert!(coordinator.is_available("llm"));
+        assert!(coordinator.is_available("unknown"));
+        
+        // Trip: only consecutive errors count
+        coordinator.record_error("llm", "timeout").unwrap();
+        coordinator.record_error("llm", "timeout").unwrap();
+        coordinator.record_activity("llm", "llm_completion", Duration::from_millis(5)).unwrap();
+        coordinator.record_error("llm", "timeout").unwrap();
+        coordinator.record_error("llm", "timeout").unwrap();
+        assert_eq!(coordinator.circuit_state("llm"), CircuitState::Closed);
+        coordinator.record_error("llm", "timeout").unwrap();
+        assert!(matches!(coordinator.circuit_state("llm"), CircuitState::Open { .. }));
+        assert!(!coordinator.is_available("llm"));
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert!(audits[0].issues.iter().any(|issue| issue.contains("Circuit breaker open")));
+        
+        // Cooldown: a single trial is let through, and a failed trial reopens
+        clock.advance(Duration::from_secs(29));
+        assert!(!coordinator.is_available("llm"));
+        clock.advance(Duration::from_secs(1));
+        assert!(coordinator.is_available("llm"));
+        assert_eq!(coordinator.circuit_state("llm"), CircuitState::HalfOpen);
+        assert!(!coordinator.is_available("llm"));
+        coordinator.record_error("llm", "timeout").unwrap();
+        assert!(!coordinator.is_available("llm"));
+        
+        // Recovery: a successful trial closes the breaker
+        clock.advance(Duration::from_secs(30));
+        assert!(coordinator.is_available("llm"));
+        coordinator.record_activity("llm", "llm_completion", Duration::from_millis(5)).unwrap();
+        assert_eq!(coordinator.circuit_state("llm"), CircuitState::Closed);
+        assert!(coordinator.is_available("llm"));
+        assert!(coordinator.is_available("llm"));
+    }
+
+    #[tokio::test]
+    async fn test_unregister_module() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["rag", "llm", "session"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("rag", "llm", 0.8).unwrap();
+        coordinator.establish_connection("session", "llm", 0.6).unwrap();
+        coordinator.establish_directed_connection("rag", "session", 0.4).unwrap();
+        
+        coordinator.unregister_module("llm").unwrap();
+        
+        let matrix = coordinator.synergy_matrix.read();
+        assert!(matrix.keys().all(|(a, b)| a != "llm" && b != "llm"), "dangling entries: {:?}", matrix);
+        assert_eq!(matrix.len(), 1);
+        drop(matrix);
+        let registry = coordinator.module_registry.read();
+        assert_eq!(registry.keys().collect::<Vec<_>>(), vec!["rag", "session"]);
+        assert!(registry.values().all(|m| !m.active_connections.contains("llm")));
+        drop(registry);
+        assert!(coordinator.audit_core_modules().unwrap().iter().all(|a| a.module_name != "llm"));
+        
+        assert!(coordinator.unregister_module("llm").is_err());
+        coordinator.register_module("llm").unwrap();
+        assert!(coordinator.module_registry.read()["llm"].active_connections.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_centrality_ranking_picks_healing_hub() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["config", "client", "rag", "llm", "repl"] {
+            coordinator.register_module(module).unwrap();
+            coordinator.record_activity(module, "llm_completion", Duration::from_millis(5)).unwrap();
+        }
+        coordinator.establish_connection("client", "rag", 0.9).unwrap();
+        coordinator.establish_connection("client", "llm", 0.9).unwrap();
+        coordinator.establish_connection("rag", "llm", 0.9).unwrap();
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        
+        let ranking = coordinator.centrality_ranking();
+        assert_eq!(ranking[0], ("client".to_string(), 0.75));
+        assert_eq!(ranking.last().unwrap(), &("repl".to_string(), 0.0));
+        assert!(ranking.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        
+        let reinforcement = ConfigReinforcement::new(HypergraphConfig::default());
+        let outcomes = reinforcement.apply_auto_healing_to(&coordinator).await.unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].action, HealingAction::Reconnect {
+            module: "repl".to_string(),
+            hub: "client".to_string(),
+            strength: 0.5,
+        });
+        assert!(coordinator.module_registry.read()["repl"].active_connections.contains("client"));
+    }
+
+    #[tokio::test]
+    async fn test_recent_errors() {
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        coordinator.register_module("llm").unwrap();
+        assert!(coordinator.recent_errors("llm").is_empty());
+        assert!(coordinator.recent_errors("missing").is_empty());
+        
+        for i in 0..60 {
+            clock.advance(Duration::from_secs(1));
+            coordinator.record_error("llm", &format!("timeout #{}", i)).unwrap();
+        }
+        let errors = coordinator.recent_errors("llm");
+        assert_eq!(errors.len(), 50);
+        assert_eq!(errors[0].1, "timeout #10");
+        assert_eq!(errors[49].1, "timeout #59");
+        assert_eq!(errors[49].0, clock.now());
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert!(audits[0].issues.contains(&"High error count detected (latest: timeout #59)".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_configured_stale_activity_overrides() {
+        let settings = HypergraphConfig {
+            stale_activity_seconds: 120,
+            stale_activity_overrides: std::collections::HashMap::from([("indexer".to_string(), 3600)]),
+            ..HypergraphConfig::default()
+        };
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config())
+            .with_clock(clock.clone())
+            .with_settings(settings).unwrap();
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("indexer").unwrap();
+        coordinator.establish_connection("client", "indexer", 0.8).unwrap();
+        
+        let stale = |coordinator: &HypergraphCoordinator| -> Vec<String> {
+            coordinator.audit_core_modules().unwrap()
+                .into_iter()
+                .filter(|a| a.issues.iter().any(|i| i == "No recent activity detected"))
+                .map(|a| a.module_name)
+                .collect()
+        };
+        clock.advance(Duration::from_secs(100));
+        assert!(stale(&coordinator).is_empty());
+        clock.advance(Duration::from_secs(100));
+        assert_eq!(stale(&coordinator), vec!["client"]);
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(stale(&coordinator), vec!["client", "indexer"]);
+        
+        // A runtime override still takes precedence over the configured one
+        coordinator.set_staleness_window("indexer", Duration::from_secs(7200)).unwrap();
+        assert_eq!(stale(&coordinator), vec!["client"]);
+    }
+
+    #[tokio::test]
+    async fn test_audit_history_between() {
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        coordinator.establish_connection("rag", "llm", 0.8).unwrap();
+        
+        let start = clock.now();
+        let mut checkpoints = Vec::new();
+        for _ in 0..4 {
+            coordinator.audit_core_modules().unwrap();
+            checkpoints.push(clock.now());
+            clock.advance(Duration::from_secs(3600));
+        }
+        
+        let all = coordinator.audit_history_between(None, start, clock.now());
+        assert_eq!(all.len(), 8);
+        let rag = coordinator.audit_history_between(Some("rag"), checkpoints[1], checkpoints[2]);
+        assert_eq!(rag.len(), 2);
+        assert!(rag.iter().all(|a| a.module_name == "rag"));
+        assert_eq!(rag[0].last_checked, checkpoints[1]);
+        assert_eq!(rag[1].last_checked, checkpoints[2]);
+        assert!(coordinator.audit_history_between(Some("missing"), start, clock.now()).is_empty());
+        assert!(coordinator.audit_history_between(None, checkpoints[3] + Duration::from_secs(1), clock.now()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_module_synergy_trend() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["rag", "llm", "session", "tools"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("rag", "llm", 0.9).unwrap();
+        coordinator.establish_connection("rag", "session", 0.9).unwrap();
+        coordinator.establish_connection("rag", "tools", 0.9).unwrap();
+        
+        coordinator.audit_core_modules().unwrap();
+        assert_eq!(coordinator.synergy_trend("rag", 5), TrendDirection::Stable);
+        
+        let mut last = Vec::new();
+        for strength in [0.7, 0.5, 0.3, 0.1] {
+            coordinator.establish_connection("rag", "llm", strength).unwrap();
+            coordinator.establish_connection("rag", "session", strength).unwrap();
+            coordinator.establish_connection("rag", "tools", strength).unwrap();
+            last = coordinator.audit_core_modules().unwrap();
+        }
+        assert_eq!(coordinator.synergy_trend("rag", 5), TrendDirection::Degrading);
+        assert_eq!(coordinator.synergy_trend("rag", 2), TrendDirection::Stable);
+        assert_eq!(coordinator.synergy_trend("missing", 5), TrendDirection::Stable);
+        let rag = last.iter().find(|a| a.module_name == "rag").unwrap();
+        assert!(rag.recommendations.contains(&"Synergy is degrading over the last 5 audits".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_prune_history_per_module() {
+        let clock = Arc::new(ManualClock::new());
+        let settings = HypergraphConfig { max_audit_history: 3, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config())
+            .with_settings(settings).unwrap()
+            .with_clock(clock.clone());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        
+        let mut checkpoints = Vec::new();
+        for _ in 0..5 {
+            coordinator.audit_core_modules().unwrap();
+            checkpoints.push(clock.now());
+            clock.advance(Duration::from_secs(1));
+        }
+        // A module registered later is not starved by the older ones
+        coordinator.register_module("repl").unwrap();
+        coordinator.audit_core_modules().unwrap();
+        checkpoints.push(clock.now());
+        
+        let history = coordinator.audit_history.read();
+        let checked = |module: &str| {
+            history.iter()
+                .filter(|audit| audit.module_name == module)
+                .map(|audit| audit.last_checked)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(checked("rag"), checkpoints[3..].to_vec());
+        assert_eq!(checked("llm"), checkpoints[3..].to_vec());
+        assert_eq!(checked("repl"), vec![checkpoints[5]]);
+        assert_eq!(history.len(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_connection_strength_bounds() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("rag").unwrap();
+        coordinator.register_module("llm").unwrap();
+        
+        for strength in [-0.5, 1.5, f64::NAN, f64::INFINITY] {
+            assert!(coordinator.establish_connection("rag", "llm", strength).is_err(), "accepted {}", strength);
+            assert!(coordinator.establish_directed_connection("rag", "llm", strength).is_err());
+        }
+        assert!(coordinator.synergy_matrix.read().is_empty());
+        assert!(coordinator.module_registry.read()["rag"].active_connections.is_empty());
+        
+        // The bounds themselves are valid
+        coordinator.establish_connection("rag", "llm", 0.0).unwrap();
+        coordinator.establish_connection("rag", "llm", 1.0).unwrap();
+        assert_eq!(coordinator.synergy_matrix.read()[&("llm".to_string(), "rag".to_string())], 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_to_dot() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["rag", "llm", "session", "config"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("rag", "llm", 0.8).unwrap();
+        coordinator.establish_directed_connection("session", "llm", 0.25).unwrap();
+        coordinator.record_interaction("rag", "llm", "embedding", 0.5).unwrap();
+        
+        let before = coordinator.to_dot();
+        assert!(before.starts_with("graph hypergraph {\n"));
+        assert!(before.contains("    \"rag\" [fillcolor=white];\n"));
+        
+        coordinator.audit_core_modules().unwrap();
+        let dot = coordinator.to_dot();
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("    \"config\" [fillcolor=lightgray];\n"));
+        assert!(dot.contains("    \"llm\" -- \"rag\" [label=\"0.80\\nembedding x1\", penwidth=4.2];\n"));
+        assert!(dot.contains("    \"llm\" -- \"session\" [label=\"- / 0.25\", penwidth=2.0];\n"));
+        assert_eq!(dot.matches(" -- ").count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_register_modules_batch() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_modules(&["client", "config", "rag"]).unwrap();
+        let names: Vec<String> = coordinator.module_registry.read().keys().cloned().collect();
+        assert_eq!(names, vec!["client", "config", "rag"]);
+        
+        // Conflicts are all reported and nothing from the batch is applied
+        let err = coordinator.register_modules(&["repl", "config", "rag"]).unwrap_err().to_string();
+        assert!(err.contains("config, rag"), "{}", err);
+        assert!(!coordinator.module_registry.read().contains_key("repl"));
+        
+        let err = coordinator.register_modules(&["repl", "serve", "repl"]).unwrap_err().to_string();
+        assert!(err.contains("repl"), "{}", err);
+        assert_eq!(coordinator.module_registry.read().len(), 3);
+        
+        coordinator.register_modules(&[]).unwrap();
+        assert_eq!(coordinator.module_registry.read().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_over_connected_module_warning() {
+        let settings = HypergraphConfig { max_connections_before_warn: 3, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+        for module in ["client", "config", "rag", "repl", "function", "serve"] {
+            coordinator.register_module(module).unwrap();
+        }
+        for peer in ["rag", "repl", "function", "serve"] {
+            coordinator.establish_connection("client", peer, 0.9).unwrap();
+            coordinator.establish_connection("config", peer, 0.9).unwrap();
+        }
+        coordinator.establish_connection("config", "client", 0.9).unwrap();
+        
+        let audits = coordinator.audit_core_modules().unwrap();
+        let client = audits.iter().find(|a| a.module_name == "client").unwrap();
+        assert_eq!(client.status, ModuleStatus::Warning);
+        assert!(client.issues.contains(&"Over-connected: 5 connections (warning above 3)".to_string()));
+        assert!(client.recommendations.iter().any(|r| r.contains("load balancing")));
+        
+        // The infrastructure hub is exempt, and modules at the threshold are fine
+        let config = audits.iter().find(|a| a.module_name == "config").unwrap();
+        assert!(!config.issues.iter().any(|i| i.starts_with("Over-connected")));
+        let rag = audits.iter().find(|a| a.module_name == "rag").unwrap();
+        assert_eq!(rag.status, ModuleStatus::Healthy);
+        assert_eq!(coordinator.over_connected_modules(), vec![("client".to_string(), 5)]);
+    }
+
+    #[tokio::test]
+    async fn test_connections_of() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["rag", "llm", "session", "tools", "config"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("rag", "llm", 0.6).unwrap();
+        coordinator.establish_connection("rag", "tools", 0.9).unwrap();
+        coordinator.establish_connection("rag", "config", 0.6).unwrap();
+        coordinator.establish_directed_connection("session", "rag", 0.7).unwrap();
+        
+        assert_eq!(coordinator.connections_of("rag"), vec![
+            ("tools".to_string(), 0.9),
+            ("config".to_string(), 0.6),
+            ("llm".to_string(), 0.6),
+            ("session".to_string(), 0.0),
+        ]);
+        assert_eq!(coordinator.connections_of("session"), vec![("rag".to_string(), 0.7)]);
+        assert!(coordinator.connections_of("missing").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_weighted_synergy_score() {
+        let score_with = |connectivity_weight: f64, strength_weight: f64| {
+            let settings = HypergraphConfig { connectivity_weight, strength_weight, ..Default::default() };
+            let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+            for module in ["rag", "llm", "session", "tools", "config"] {
+                coordinator.register_module(module).unwrap();
+            }
+            // One of four possible peers, at full strength
+            coordinator.establish_connection("rag", "llm", 1.0).unwrap();
+            let audits = coordinator.audit_core_modules().unwrap();
+            audits.iter().find(|a| a.module_name == "rag").unwrap().synergy_score
+        };
+        
+        assert!((score_with(0.5, 0.5) - 0.625).abs() < 1e-9);
+        assert!((score_with(0.1, 0.9) - 0.925).abs() < 1e-9);
+        assert!((score_with(1.0, 0.0) - 0.25).abs() < 1e-9);
+        
+        let invalid = HypergraphConfig { connectivity_weight: 0.7, strength_weight: 0.7, ..Default::default() };
+        assert!(invalid.validate().is_err());
+        assert!(HypergraphCoordinator::new(create_test_config()).with_settings(invalid).is_err());
+        let negative = HypergraphConfig { connectivity_weight: -0.5, strength_weight: 1.5, ..Default::default() };
+        assert!(negative.validate().is_err());
+        assert!(HypergraphConfig::default().validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_operation_latency_stats() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        
+        for ms in 1..=100 {
+            coordinator.record_activity("client", "llm_completion", Duration::from_millis(ms * 10)).unwrap();
+        }
+        for _ in 0..10 {
+            coordinator.record_activity("rag", "embedding", Duration::from_millis(5)).unwrap();
+        }
+        
+        let slow = coordinator.latency_stats("llm_completion").unwrap();
+        assert_eq!(slow.count, 100);
+        assert_eq!(slow.min_ms, 10.0);
+        assert_eq!(slow.max_ms, 1000.0);
+        // Estimates land within one bucket (about 19%) of the true percentiles
+        assert!(slow.p50_ms >= 500.0 && slow.p50_ms <= 500.0 * 1.2, "p50 {}", slow.p50_ms);
+        assert!(slow.p95_ms >= 950.0 && slow.p95_ms <= 1000.0, "p95 {}", slow.p95_ms);
+        
+        let fast = coordinator.latency_stats("embedding").unwrap();
+        assert_eq!((fast.count, fast.p50_ms, fast.p95_ms), (10, 5.0, 5.0));
+        assert!(coordinator.latency_stats("rag_query").is_none());
+        
+        let report = coordinator.generate_health_report_json().unwrap();
+        let latencies = report["operation_latencies"].as_object().unwrap();
+        assert_eq!(latencies.keys().collect::<Vec<_>>(), vec!["embedding", "llm_completion"]);
+        assert_eq!(latencies["llm_completion"]["count"], 100);
+    }
+
+    #[tokio::test]
+    async fn test_auto_healing_strengthens_weak_connections() {
+        let clock = Arc::new(ManualClock::new());
+        let settings = HypergraphConfig { stale_activity_seconds: 60, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config())
+            .with_settings(settings.clone()).unwrap()
+            .with_clock(clock.clone());
+        for module in ["client", "config", "rag", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        coordinator.establish_connection("rag", "client", 0.3).unwrap();
+        coordinator.establish_connection("repl", "config", 0.55).unwrap();
+        coordinator.establish_connection("repl", "rag", 0.2).unwrap();
+        
+        // repl has gone quiet, so its weak connections are left alone
+        clock.advance(Duration::from_secs(120));
+        for module in ["client", "config", "rag"] {
+            coordinator.record_activity(module, "llm_completion", Duration::from_millis(5)).unwrap();
+        }
+        
+        let reinforcement = ConfigReinforcement::new(settings);
+        let outcomes = reinforcement.apply_auto_healing_to(&coordinator).await.unwrap();
+        let actions: Vec<&HealingAction> = outcomes.iter().map(|o| &o.action).collect();
+        assert_eq!(actions, vec![&HealingAction::Strengthen {
+            module_a: "client".to_string(),
+            module_b: "rag".to_string(),
+            from: 0.3,
+            to: 0.4,
+        }]);
+        assert!(outcomes[0].result.is_ok());
+        assert!(outcomes[0].action.to_string().contains("from 0.30 to 0.40"));
+        let matrix = coordinator.synergy_matrix.read();
+        assert!((matrix[&("rag".to_string(), "client".to_string())] - 0.4).abs() < 1e-9);
+        assert!((matrix[&("client".to_string(), "rag".to_string())] - 0.4).abs() < 1e-9);
+        assert_eq!(matrix[&("repl".to_string(), "rag".to_string())], 0.2);
+    }
+
+    #[tokio::test]
+    async fn test_plan_auto_healing_dry_run() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "rag", "repl"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        coordinator.establish_connection("rag", "client", 0.3).unwrap();
+        let matrix_before = coordinator.synergy_matrix.read().clone();
+        
+        let reinforcement = ConfigReinforcement::new(HypergraphConfig::default());
+        let plan = reinforcement.plan_auto_healing_to(&coordinator).await.unwrap();
+        let reconnect = HealingAction::Reconnect {
+            module: "repl".to_string(),
+            hub: "client".to_string(),
+            strength: 0.5,
+        };
+        let strengthen = HealingAction::Strengthen {
+            module_a: "client".to_string(),
+            module_b: "rag".to_string(),
+            from: 0.3,
+            to: 0.4,
+        };
+        assert_eq!(plan, vec![reconnect.clone(), strengthen.clone()]);
+        // Planning leaves the topology untouched
+        assert_eq!(*coordinator.synergy_matrix.read(), matrix_before);
+        assert!(coordinator.module_registry.read()["repl"].active_connections.is_empty());
+        
+        // Operators can apply only the actions they trust
+        let selected: Vec<HealingAction> = plan.into_iter()
+            .filter(|action| matches!(action, HealingAction::Strengthen { .. }))
+            .collect();
+        let outcomes = reinforcement.apply_healing_actions_to(&coordinator, selected);
+        assert_eq!(outcomes, vec![HealingOutcome { action: strengthen, result: Ok(()) }]);
+        assert!(coordinator.module_registry.read()["repl"].active_connections.is_empty());
+        assert!((coordinator.synergy_matrix.read()[&("rag".to_string(), "client".to_string())] - 0.4).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_validate_critical_paths() {
+        let reinforcement = ConfigReinforcement::new(HypergraphConfig::default());
+        let config = create_test_config();
+        config.write().clients = serde_yaml::from_str(r#"
+- type: openai
+  api_key: test
+- type: openai-compatible
+  name: ""
+- type: openai-compatible
+  name: local
+  api_base: localhost:8080/v1
+- type: claude
+  api_base: https://proxy.example.com/v1
+- type: no-such-client
+"#).unwrap();
+        config.write().model_id = "mistral:large".to_string();
+        config.write().rag_embedding_model = Some(":embed".to_string());
+        config.write().rag_reranker_model = Some("claude:rerank".to_string());
+        
+        let recommendations = reinforcement.validate_critical_paths(&config).unwrap();
+        let has = |text: &str| recommendations.iter().any(|r| r.contains(text));
+        assert!(has("Client #2 has an empty provider name"), "{:?}", recommendations);
+        assert!(has("Client 'local' has an invalid api_base 'localhost:8080/v1'"));
+        assert!(!has("Client 'claude'"));
+        assert!(has("Client #5 has an unknown type"));
+        assert!(has("model 'mistral:large' refers to provider 'mistral'"));
+        assert!(has("rag_embedding_model ':embed' has an empty provider name"));
+        assert!(!has("rag_reranker_model"));
+        assert!(!has("validated successfully"));
+    }
+
+    #[tokio::test]
+    async fn test_strongest_path_across_components() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["rag", "client", "session", "config", "repl", "tools"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("rag", "client", 0.8).unwrap();
+        coordinator.establish_connection("client", "session", 0.9).unwrap();
+        coordinator.establish_connection("rag", "config", 0.9).unwrap();
+        coordinator.establish_connection("config", "session", 0.5).unwrap();
+        coordinator.establish_connection("repl", "tools", 0.9).unwrap();
+        
+        // rag output reaches session through client (0.72) rather than config (0.45)
+        assert_eq!(coordinator.strongest_path("rag", "session").unwrap(), vec!["rag", "client", "session"]);
+        let (_, strength) = coordinator.strongest_path_with_strength("rag", "session", false).unwrap();
+        assert!(approx_eq(strength, 0.72));
+        
+        assert!(coordinator.strongest_path("rag", "tools").is_none());
+        assert!(coordinator.strongest_path("rag", "missing").is_none());
+        assert!(coordinator.strongest_path("rag", "rag").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_error_rate_threshold() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.9).unwrap();
+        for _ in 0..16 {
+            coordinator.record_activity("client", "llm_completion", Duration::from_millis(5)).unwrap();
+        }
+        for _ in 0..4 {
+            coordinator.record_activity("rag", "rag_query", Duration::from_millis(5)).unwrap();
+        }
+        for i in 0..3 {
+            coordinator.record_error("client", &format!("timeout {}", i)).unwrap();
+        }
+        coordinator.record_error("rag", "index missing").unwrap();
+        
+        // 4 errors over 20 operations
+        assert!(approx_eq(coordinator.get_performance_metrics().error_rate, 0.2));
+        let strict = ConfigReinforcement::new(HypergraphConfig::default());
+        let recommendations = strict.error_threshold_recommendations(&coordinator);
+        assert_eq!(recommendations, vec![
+            "High error rate detected (20.0% of operations, threshold 10.0%). Consider implementing circuit breakers.".to_string(),
+        ]);
+        
+        let lenient = ConfigReinforcement::new(HypergraphConfig { max_error_rate: 0.25, ..Default::default() });
+        assert!(lenient.error_threshold_recommendations(&coordinator).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stuck_operations() {
+        let clock = Arc::new(ManualClock::new());
+        let settings = HypergraphConfig { stuck_operation_seconds: 60, ..Default::default() };
+        let coordinator = HypergraphCoordinator::new(create_test_config())
+            .with_settings(settings).unwrap()
+            .with_clock(clock.clone());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.9).unwrap();
+        
+        let hung = coordinator.begin_operation("rag", "rag_query").unwrap();
+        clock.advance(Duration::from_secs(50));
+        {
+            let _quick = coordinator.begin_operation("client", "llm_completion").unwrap();
+            assert_eq!(coordinator.in_flight_operations("client"), 1);
+            clock.advance(Duration::from_secs(2));
+        }
+        assert_eq!(coordinator.in_flight_operations("client"), 0);
+        assert_eq!(coordinator.module_registry.read()["client"].message_count, 1);
+        assert_eq!(coordinator.latency_stats("llm_completion").unwrap().max_ms, 2000.0);
+        assert!(coordinator.latency_stats("rag_query").is_none());
+        assert!(coordinator.begin_operation("missing", "llm_completion").is_err());
+        
+        clock.advance(Duration::from_secs(20));
+        let audits = coordinator.audit_core_modules().unwrap();
+        let rag = audits.iter().find(|a| a.module_name == "rag").unwrap();
+        assert_eq!(rag.status, ModuleStatus::Warning);
+        assert!(rag.issues.contains(&"Potentially stuck: 1 operations in flight for over 60s".to_string()));
+        let client = audits.iter().find(|a| a.module_name == "client").unwrap();
+        assert!(!client.issues.iter().any(|i| i.starts_with("Potentially stuck")));
+        
+        // Finishing the operation clears the flag
+        drop(hung);
+        assert_eq!(coordinator.in_flight_operations("rag"), 0);
+        let audits = coordinator.audit_core_modules().unwrap();
+        assert_eq!(audits.iter().find(|a| a.module_name == "rag").unwrap().status, ModuleStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_module_audit_serde_round_trip() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        coordinator.register_module("client").unwrap();
+        coordinator.register_module("rag").unwrap();
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        coordinator.set_gauge("rag", "queue_depth", 3.0).unwrap();
+        let audits = coordinator.audit_core_modules().unwrap();
+        
+        let json = serde_json::to_string(&audits).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[1]["status"], "Healthy");
+        assert!(value[1]["last_checked"].as_f64().unwrap() > 1.0e9);
+        
+        let restored: Vec<ModuleAudit> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.len(), audits.len());
+        for (original, restored) in audits.iter().zip(&restored) {
+            assert_eq!(restored.module_name, original.module_name);
+            assert_eq!(restored.status, original.status);
+            assert_eq!(restored.synergy_score, original.synergy_score);
+            assert_eq!(restored.issues, original.issues);
+            assert_eq!(restored.gauges, original.gauges);
+            let drift = if restored.last_checked > original.last_checked {
+                restored.last_checked - original.last_checked
+            } else {
+                original.last_checked - restored.last_checked
+            };
+            assert!(drift < Duration::from_millis(50), "drifted by {:?}", drift);
+        }
+        
+        let status: ModuleStatus = serde_json::from_str("\"Critical\"").unwrap();
+        assert_eq!(status, ModuleStatus::Critical);
+    }
+
+    #[tokio::test]
+    async fn test_cognitive_efficiency() {
+        assert_eq!(HypergraphCoordinator::new(create_test_config()).cognitive_efficiency(), 1.0);
+        
+        // Same memory and operation count, only the operation durations differ
+        let run = |duration: Duration| {
+            let coordinator = HypergraphCoordinator::new(create_test_config());
+            coordinator.register_module("client").unwrap();
+            coordinator.register_module("rag").unwrap();
+            coordinator.update_memory_usage("client", 4000).unwrap();
+            for _ in 0..4 {
+                coordinator.record_activity("rag", "hypergraph_update", duration).unwrap();
+            }
+            coordinator.get_performance_metrics()
+        };
+        let light = run(Duration::from_millis(10));
+        let heavy = run(Duration::from_secs(5));
+        
+        assert_eq!(light.memory_efficiency, heavy.memory_efficiency);
+        assert!(light.cognitive_efficiency < light.memory_efficiency);
+        assert!(heavy.cognitive_efficiency < light.cognitive_efficiency);
+    }
+
+    #[tokio::test]
+    async fn test_merge_from() {
+        let worker = |connections: &[(&str, &str, f64)], ops: u64, errors: u64| {
+            let coordinator = HypergraphCoordinator::new(create_test_config());
+            for module in ["client", "rag", "llm"] {
+                coordinator.register_module(module).unwrap();
+            }
+            for (a, b, strength) in connections {
+                coordinator.establish_connection(a, b, *strength).unwrap();
+            }
+            for _ in 0..ops {
+                coordinator.record_activity("client", "llm_completion", Duration::from_millis(10)).unwrap();
+            }
+            for i in 0..errors {
+                coordinator.record_error("client", &format!("error {}", i)).unwrap();
+            }
+            coordinator
+        };
+        let first = worker(&[("client", "rag", 0.4)], 5, 1);
+        let second = worker(&[("client", "rag", 0.8), ("rag", "llm", 0.6)], 7, 2);
+        second.register_module("tools").unwrap();
+        
+        first.merge_from(&second).unwrap();
+        let registry = first.module_registry.read();
+        assert_eq!(registry["client"].message_count, 12);
+        assert_eq!(registry["client"].error_count, 3);
+        assert!(registry["rag"].active_connections.contains("llm"));
+        assert!(registry["rag"].active_connections.contains("client"));
+        assert!(registry.contains_key("tools"));
+        drop(registry);
+        let matrix = first.synergy_matrix.read();
+        assert_eq!(matrix[&("client".to_string(), "rag".to_string())], 0.8);
+        assert_eq!(matrix[&("llm".to_string(), "rag".to_string())], 0.6);
+        drop(matrix);
+        assert_eq!(first.get_performance_metrics().total_operations, 12);
+        assert!(approx_eq(first.get_performance_metrics().error_rate, 0.25));
+        // The source is untouched
+        assert_eq!(second.module_registry.read()["client"].message_count, 7);
+        assert!(first.merge_from(&first).is_err());
+        
+        let settings = HypergraphConfig { merge_strength_policy: MergeStrengthPolicy::Average, ..Default::default() };
+        let averaged = worker(&[("client", "rag", 0.4)], 0, 0).with_settings(settings).unwrap();
+        averaged.merge_from(&second).unwrap();
+        assert!(approx_eq(averaged.synergy_matrix.read()[&("rag".to_string(), "client".to_string())], 0.6));
+        
+        // Folding workers one at a time is a plain mean, not a decaying one
+        let settings = HypergraphConfig { merge_strength_policy: MergeStrengthPolicy::Average, ..Default::default() };
+        let fleet = HypergraphCoordinator::new(create_test_config()).with_settings(settings.clone()).unwrap();
+        for strength in [0.2, 0.5, 0.8, 0.9] {
+            fleet.merge_from(&worker(&[("client", "rag", strength)], 0, 0)).unwrap();
+        }
+        assert!(approx_eq(fleet.synergy_matrix.read()[&("client".to_string(), "rag".to_string())], 0.6));
+        // Merging partial aggregates weights them by their worker counts
+        let partial = worker(&[("client", "rag", 0.2)], 0, 0).with_settings(settings.clone()).unwrap();
+        partial.merge_from(&worker(&[("client", "rag", 0.5)], 0, 0)).unwrap();
+        let last = worker(&[("client", "rag", 0.8)], 0, 0).with_settings(settings).unwrap();
+        last.merge_from(&partial).unwrap();
+        assert!(approx_eq(last.synergy_matrix.read()[&("client".to_string(), "rag".to_string())], 0.5));
+    }
+
+    #[tokio::test]
+    async fn test_smoothing_alphas() {
+        let run = |alpha: f64| {
+            let settings = HypergraphConfig {
+                cognitive_load_alpha: alpha,
+                response_time_alpha: alpha,
+                ..Default::default()
+            };
+            let coordinator = HypergraphCoordinator::new(create_test_config()).with_settings(settings).unwrap();
+            coordinator.register_module("client").unwrap();
+            for _ in 0..20 {
+                coordinator.record_activity("client", "llm_completion", Duration::from_millis(10)).unwrap();
+            }
+            // Latency steps up to one second
+            for _ in 0..3 {
+                coordinator.record_activity("client", "llm_completion", Duration::from_secs(1)).unwrap();
+            }
+            let load = coordinator.module_registry.read()["client"].cognitive_load;
+            (coordinator.get_performance_metrics().average_response_time, load)
+        };
+        let (slow_latency, slow_load) = run(0.1);
+        let (fast_latency, fast_load) = run(0.5);
+        assert!(slow_latency < Duration::from_millis(400), "{:?}", slow_latency);
+        assert!(fast_latency > Duration::from_millis(800), "{:?}", fast_latency);
+        assert!(fast_load > slow_load);
+        
+        for alpha in [0.0, 1.0, -0.1, f64::NAN] {
+            let settings = HypergraphConfig { response_time_alpha: alpha, ..Default::default() };
+            assert!(settings.validate().is_err(), "accepted {}", alpha);
+            // The smoothing would otherwise reach record_activity unchecked
+            assert!(HypergraphCoordinator::new(create_test_config()).with_settings(settings).is_err());
+        }
+        let too_large = HypergraphConfig { cognitive_load_alpha: 1.5, ..Default::default() };
+        assert!(too_large.validate().is_err());
+        assert!(HypergraphCoordinator::new(create_test_config()).with_settings(too_large).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reset_counters() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "rag", "llm"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "rag", 0.8).unwrap();
+        coordinator.establish_connection("rag", "llm", 0.7).unwrap();
+        for module in ["client", "rag"] {
+            for _ in 0..5 {
+                coordinator.record_activity(module, "hypergraph_update", Duration::from_secs(2)).unwrap();
+            }
+            coordinator.record_error(module, "timeout").unwrap();
+        }
+        let matrix_before = coordinator.synergy_matrix.read().clone();
+        
+        coordinator.reset_counters(Some("rag"), false).unwrap();
+        {
+            let registry = coordinator.module_registry.read();
+            assert_eq!((registry["rag"].message_count, registry["rag"].error_count), (0, 0));
+            assert!(registry["rag"].cognitive_load > 0.0);
+            assert_eq!((registry["client"].message_count, registry["client"].error_count), (5, 1));
+        }
+        assert_eq!(coordinator.get_performance_metrics().total_operations, 5);
+        assert!(coordinator.recent_errors("rag").is_empty());
+        
+        coordinator.reset_counters(None, true).unwrap();
+        let registry = coordinator.module_registry.read();
+        assert!(registry.values().all(|m| m.message_count == 0 && m.error_count == 0 && m.cognitive_load == 0.0));
+        // Connections survive the reset
+        assert!(registry["rag"].active_connections.contains("client"));
+        assert!(registry["rag"].active_connections.contains("llm"));
+        drop(registry);
+        assert_eq!(*coordinator.synergy_matrix.read(), matrix_before);
+        assert_eq!(coordinator.get_performance_metrics().error_rate, 0.0);
+        assert_eq!(coordinator.module_errors_in_window("client", Duration::from_secs(3600)), 0);
+        assert!(coordinator.reset_counters(Some("missing"), false).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_replay_connection_ttls_and_unregister() {
+        let path = std::env::temp_dir().join(format!("hypergraph-replay-{}.jsonl", uuid::Uuid::new_v4()));
+        let clock = Arc::new(ManualClock::new());
+        let coordinator = HypergraphCoordinator::new(create_test_config()).with_clock(clock.clone());
+        coordinator.start_recording(&path).unwrap();
+        
+        for module in ["client", "rag", "repl", "scratch"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection_ttl("client", "rag", 0.8, Duration::from_secs(60)).unwrap();
+        coordinator.establish_connection_idle_ttl("rag", "repl", 0.6, Duration::from_secs(30)).unwrap();
+        coordinator.establish_connection("client", "repl", 0.7).unwrap();
+        coordinator.establish_connection("repl", "scratch", 0.5).unwrap();
+        coordinator.unregister_module("scratch").unwrap();
+        // Both TTLs lapse; only client stays active
+        clock.advance(Duration::from_secs(90));
+        coordinator.record_activity("client", "llm_completion", Duration::from_millis(40)).unwrap();
+        coordinator.stop_recording();
+        
+        let replayed = HypergraphCoordinator::replay_until(create_test_config(), &path, u64::MAX).unwrap();
+        assert!(!replayed.module_registry.read().contains_key("scratch"));
+        assert_eq!(replayed.expire_edges(), 2);
+        assert_eq!(replayed.connections_of("client"), vec![("repl".to_string(), 0.7)]);
+        assert!(replayed.connections_of("rag").is_empty());
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_strengthen_keeps_directions() {
+        let coordinator = HypergraphCoordinator::new(create_test_config());
+        for module in ["client", "config", "rag", "llm"] {
+            coordinator.register_module(module).unwrap();
+        }
+        coordinator.establish_connection("client", "config", 0.9).unwrap();
+        coordinator.establish_directed_connection("rag", "client", 0.3).unwrap();
+        coordinator.establish_directed_connection("llm", "config", 0.2).unwrap();
+        coordinator.establish_directed_connection("config", "llm", 0.1).unwrap();
+        
+        let reinforcement = ConfigReinforcement::new(HypergraphConfig::default());
+        let outcomes = reinforcement.apply_auto_healing_to(&coordinator).await.unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|outcome| outcome.result.is_ok()));
+        
+        let matrix = coordinator.synergy_matrix.read();
+        let strength = |a: &str, b: &str| matrix.get(&(a.to_string(), b.to_string())).copied();
+        // The directed edge is raised without gaining a reverse direction
+        assert!((strength("rag", "client").unwrap() - 0.4).abs() < 1e-9);
+        assert_eq!(strength("client", "rag"), None);
+        // Both directions of the asymmetric pair rise by the same increment
+        assert!((strength("llm", "config").unwrap() - 0.3).abs() < 1e-9);
+        assert!((strength("config", "llm").unwrap() - 0.2).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_cli_hypergraph_report_flags() {
+        use clap::Parser;
+        use crate::cli::Cli;
+        
+        // Plain prompts, including ones starting with "hypergraph", stay chat text
+        for args in [&["aichat", "help", "me", "write", "a", "poem"][..], &["aichat", "hypergraph", "is", "a", "weird", "word"]] {
+            let cli = Cli::try_parse_from(args).unwrap();
+            assert!(!cli.hypergraph_report);
+            assert!(format!("{:?}", cli).contains(&format!("{:?}", &args[1..])));
+        }
+        
+        let cli = Cli::try_parse_from(["aichat", "--hypergraph-report", "--json", "--watch", "5"]).unwrap();
+        assert!(cli.hypergraph_report && cli.json);
+        assert_eq!(cli.watch, Some(5));
+        assert!(Cli::try_parse_from(["aichat", "--watch", "5"]).is_err());
     }
-}
\ No newline at end of file
+}